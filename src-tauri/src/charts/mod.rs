@@ -0,0 +1,9 @@
+pub mod raster;
+pub mod renderer;
+pub mod svg;
+pub mod theme;
+
+pub use raster::*;
+pub use renderer::*;
+pub use svg::*;
+pub use theme::*;
@@ -0,0 +1,20 @@
+use resvg::{tiny_skia, usvg};
+
+use crate::charts::{FONT_BOLD, FONT_FAMILY, FONT_REGULAR};
+
+// Hanya font bawaan yang dimuat, jadi hasil PNG sama di semua mesin
+pub fn svg_to_png(svg: &str) -> Result<Vec<u8>, String> {
+    let mut options = usvg::Options {
+        font_family: FONT_FAMILY.to_string(),
+        ..Default::default()
+    };
+    options.fontdb_mut().load_font_data(FONT_REGULAR.to_vec());
+    options.fontdb_mut().load_font_data(FONT_BOLD.to_vec());
+
+    let tree = usvg::Tree::from_str(svg, &options).map_err(|e| format!("SVG error: {}", e))?;
+    let size = tree.size().to_int_size();
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| "PNG error: invalid image size".to_string())?;
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    pixmap.encode_png().map_err(|e| format!("PNG error: {}", e))
+}
@@ -0,0 +1,572 @@
+use chrono::{Datelike, NaiveDate};
+use std::collections::BTreeMap;
+use std::f32::consts::PI;
+
+use crate::charts::{category_color, polar, Anchor, ChartTheme, SvgCanvas, TextStyle};
+use crate::i18n::{format_number, format_percent, text, tr, Locale};
+use crate::models::{MonthlyStats, SeriesReport, WeeklyStats};
+
+// Hitungan harian dan per kategori untuk satu bulan kalender, diambil dari minggu-minggu
+// di MonthlyStats (hari di luar bulan ikut ada di minggu pertama/terakhir)
+pub struct MonthActivity {
+    pub first_day: NaiveDate,
+    pub daily: BTreeMap<NaiveDate, usize>,
+    pub categories: BTreeMap<String, usize>,
+}
+
+impl MonthActivity {
+    pub fn from_stats(stats: &MonthlyStats, month: u32) -> Option<Self> {
+        let first_day = NaiveDate::from_ymd_opt(stats.year, month, 1)?;
+        let mut daily = BTreeMap::new();
+        let mut categories = BTreeMap::new();
+        for day in stats.weeks.iter().flat_map(|w| &w.days) {
+            let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") else {
+                continue;
+            };
+            if date.month() != month || date.year() != stats.year {
+                continue;
+            }
+            daily.insert(date, day.total_habits);
+            for count in &day.by_category {
+                *categories.entry(count.category.clone()).or_insert(0) += count.count;
+            }
+        }
+        Some(Self {
+            first_day,
+            daily,
+            categories,
+        })
+    }
+}
+
+pub fn weekday_short(locale: Locale, weekday: u32) -> String {
+    text(locale, &format!("weekday.{}", weekday))
+        .chars()
+        .take(3)
+        .collect()
+}
+
+// Langkah sumbu y 1/2/5 x 10^n, minimal `min_step`
+fn axis_step(max: f64, min_step: f64) -> f64 {
+    let raw = (max / 4.0).max(min_step);
+    let magnitude = 10f64.powf(raw.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|step| *step >= raw)
+        .unwrap_or(raw)
+        .max(min_step)
+}
+
+struct PlotArea {
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+}
+
+impl PlotArea {
+    fn width(&self) -> f32 {
+        self.right - self.left
+    }
+
+    fn height(&self) -> f32 {
+        self.bottom - self.top
+    }
+}
+
+pub struct ChartRenderer {
+    pub width: u32,
+    pub height: u32,
+    pub theme: ChartTheme,
+    pub locale: Locale,
+}
+
+impl ChartRenderer {
+    fn canvas(&self) -> SvgCanvas {
+        SvgCanvas::new(self.width, self.height, self.theme)
+    }
+
+    // Judul di kiri atas, sisanya area plot dengan ruang untuk label sumbu
+    fn frame(&self, canvas: &mut SvgCanvas, title: &str) -> PlotArea {
+        let u = canvas.unit();
+        let pad = 24.0 * u;
+        canvas.text(
+            pad,
+            pad + 16.0 * u,
+            title,
+            TextStyle::new(18.0 * u, self.theme.text).bold(),
+        );
+        PlotArea {
+            left: pad + 36.0 * u,
+            top: pad + 44.0 * u,
+            right: canvas.width - pad,
+            bottom: canvas.height - pad - 24.0 * u,
+        }
+    }
+
+    fn empty(&self, canvas: &mut SvgCanvas, plot: &PlotArea) {
+        let u = canvas.unit();
+        canvas.text(
+            plot.left + plot.width() / 2.0,
+            plot.top + plot.height() / 2.0,
+            &text(self.locale, "chart.no_data"),
+            TextStyle::new(14.0 * u, self.theme.muted).anchor(Anchor::Middle),
+        );
+    }
+
+    // Garis grid horizontal dan label sumbu y, mengembalikan nilai puncak sumbu
+    fn y_axis(&self, canvas: &mut SvgCanvas, plot: &PlotArea, max: f64, min_step: f64) -> f64 {
+        let u = canvas.unit();
+        let step = axis_step(max, min_step);
+        let ticks = (max / step).ceil().max(1.0) as usize;
+        let top_value = step * ticks as f64;
+        let decimals = if step.fract() == 0.0 { 0 } else { 1 };
+        for i in 0..=ticks {
+            let value = step * i as f64;
+            let y = plot.bottom - (value / top_value) as f32 * plot.height();
+            canvas.line((plot.left, y), (plot.right, y), self.theme.border, 1.0 * u);
+            canvas.text(
+                plot.left - 8.0 * u,
+                y + 4.0 * u,
+                &format_number(self.locale, value, decimals),
+                TextStyle::new(11.0 * u, self.theme.muted).anchor(Anchor::End),
+            );
+        }
+        top_value
+    }
+
+    pub fn weekly_bars(&self, stats: &WeeklyStats) -> String {
+        let mut canvas = self.canvas();
+        let u = canvas.unit();
+        let title = tr(
+            self.locale,
+            "chart.weekly_title",
+            &[
+                ("start", stats.week_start.clone()),
+                ("end", stats.week_end.clone()),
+            ],
+        );
+        let plot = self.frame(&mut canvas, &title);
+
+        let max = stats.days.iter().map(|d| d.total_habits).max().unwrap_or(0);
+        if max == 0 {
+            self.empty(&mut canvas, &plot);
+            return canvas.finish();
+        }
+        let top_value = self.y_axis(&mut canvas, &plot, max as f64, 1.0);
+        let slot = plot.width() / 7.0;
+        let bar_width = slot * 0.6;
+
+        for (i, day) in stats.days.iter().enumerate().take(7) {
+            let x = plot.left + slot * i as f32 + (slot - bar_width) / 2.0;
+            let height = (day.total_habits as f64 / top_value) as f32 * plot.height();
+            if day.total_habits > 0 {
+                canvas.rect(
+                    x,
+                    plot.bottom - height,
+                    bar_width,
+                    height,
+                    self.theme.primary,
+                    (6.0 * u).min(height / 2.0),
+                );
+                canvas.text(
+                    x + bar_width / 2.0,
+                    plot.bottom - height - 6.0 * u,
+                    &day.total_habits.to_string(),
+                    TextStyle::new(12.0 * u, self.theme.text)
+                        .anchor(Anchor::Middle)
+                        .bold(),
+                );
+            }
+            canvas.text(
+                x + bar_width / 2.0,
+                plot.bottom + 18.0 * u,
+                &weekday_short(self.locale, i as u32),
+                TextStyle::new(12.0 * u, self.theme.muted).anchor(Anchor::Middle),
+            );
+        }
+        canvas.finish()
+    }
+
+    pub fn monthly_heatmap(&self, stats: &MonthlyStats, activity: &MonthActivity) -> String {
+        let mut canvas = self.canvas();
+        let u = canvas.unit();
+        let title = format!(
+            "{} · {} {}",
+            text(self.locale, "report.heatmap"),
+            stats.month,
+            stats.year
+        );
+        let plot = self.frame(&mut canvas, &title);
+        let header = 18.0 * u;
+
+        let offset = activity.first_day.weekday().num_days_from_monday() as usize;
+        let rows = (offset + activity.daily.len()).div_ceil(7).max(1);
+        // Heatmap tidak butuh sumbu y, jadi area plot dilebarkan ke kiri
+        let left = plot.left - 36.0 * u;
+        let pitch = ((plot.right - left) / 7.0).min((plot.height() - header) / rows as f32);
+        let gap = pitch * 0.1;
+        let cell = pitch - gap;
+        let grid_left = left + ((plot.right - left) - pitch * 7.0) / 2.0;
+        let grid_top = plot.top + header;
+
+        for weekday in 0..7 {
+            canvas.text(
+                grid_left + pitch * weekday as f32 + cell / 2.0,
+                plot.top + 12.0 * u,
+                &weekday_short(self.locale, weekday),
+                TextStyle::new(11.0 * u, self.theme.muted).anchor(Anchor::Middle),
+            );
+        }
+
+        let max = activity.daily.values().copied().max().unwrap_or(0).max(1);
+        for (date, count) in &activity.daily {
+            let index = offset + (*date - activity.first_day).num_days() as usize;
+            let x = grid_left + pitch * (index % 7) as f32;
+            let y = grid_top + pitch * (index / 7) as f32;
+            let intensity = *count as f32 / max as f32;
+            canvas.rect(x, y, cell, cell, self.theme.scale(intensity), 4.0 * u);
+
+            let label = if intensity > 0.5 {
+                self.theme.background
+            } else {
+                self.theme.text
+            };
+            let small = (cell * 0.22).min(11.0 * u);
+            canvas.text(
+                x + cell * 0.1,
+                y + small + cell * 0.06,
+                &date.day().to_string(),
+                TextStyle::new(small, label),
+            );
+            if *count > 0 {
+                canvas.text(
+                    x + cell / 2.0,
+                    y + cell * 0.78,
+                    &count.to_string(),
+                    TextStyle::new((cell * 0.32).min(16.0 * u), label)
+                        .anchor(Anchor::Middle)
+                        .bold(),
+                );
+            }
+        }
+
+        // Legenda di kanan atas, sejajar judul
+        let square = 10.0 * u;
+        let legend_right = canvas.width - 24.0 * u;
+        let legend_y = 24.0 * u + 6.0 * u;
+        canvas.text(
+            legend_right,
+            legend_y + square - 1.0 * u,
+            &max.to_string(),
+            TextStyle::new(11.0 * u, self.theme.muted).anchor(Anchor::End),
+        );
+        let squares_right = legend_right - 6.0 * u - 8.0 * u * max.to_string().len() as f32;
+        for (i, t) in [0.0, 0.33, 0.66, 1.0].iter().rev().enumerate() {
+            let x = squares_right - (i as f32 + 1.0) * (square + 3.0 * u);
+            canvas.rect(x, legend_y, square, square, self.theme.scale(*t), 2.0 * u);
+        }
+        canvas.text(
+            squares_right - 4.0 * (square + 3.0 * u) - 6.0 * u,
+            legend_y + square - 1.0 * u,
+            "0",
+            TextStyle::new(11.0 * u, self.theme.muted).anchor(Anchor::End),
+        );
+        canvas.finish()
+    }
+
+    pub fn strength_line(&self, series: &SeriesReport) -> String {
+        let mut canvas = self.canvas();
+        let u = canvas.unit();
+        let subject = [
+            &series.filter.habit_name,
+            &series.filter.category,
+            &series.filter.tag,
+        ]
+        .into_iter()
+        .find_map(|s| s.clone());
+        let title = match subject {
+            Some(subject) => format!(
+                "{} · {}",
+                text(self.locale, "chart.strength_title"),
+                subject
+            ),
+            None => text(self.locale, "chart.strength_title"),
+        };
+        let plot = self.frame(&mut canvas, &title);
+
+        let lines: [(Vec<Option<f64>>, u32, f32, &str); 3] = [
+            (
+                series.points.iter().map(|p| p.value).collect(),
+                self.theme.baseline,
+                1.5,
+                "chart.daily",
+            ),
+            (
+                series.points.iter().map(|p| p.rolling_7).collect(),
+                self.theme.primary,
+                3.0,
+                "chart.rolling_7",
+            ),
+            (
+                series.points.iter().map(|p| p.rolling_30).collect(),
+                self.theme.accent,
+                3.0,
+                "chart.rolling_30",
+            ),
+        ];
+        let max = lines
+            .iter()
+            .flat_map(|(values, ..)| values.iter().flatten())
+            .fold(0.0f64, |a, b| a.max(*b));
+        if series.points.is_empty() || max <= 0.0 {
+            self.empty(&mut canvas, &plot);
+            return canvas.finish();
+        }
+
+        let top_value = self.y_axis(&mut canvas, &plot, max, 0.5);
+        let count = series.points.len();
+        let x_of = |i: usize| {
+            if count == 1 {
+                plot.left + plot.width() / 2.0
+            } else {
+                plot.left + plot.width() * i as f32 / (count - 1) as f32
+            }
+        };
+
+        for (values, color, width, _) in &lines {
+            // Titik tanpa nilai memutus garis, tidak dianggap nol
+            let mut segment = Vec::new();
+            for (i, value) in values.iter().enumerate() {
+                match value {
+                    Some(v) => segment.push((
+                        x_of(i),
+                        plot.bottom - (*v / top_value) as f32 * plot.height(),
+                    )),
+                    None => {
+                        canvas.polyline(&segment, *color, width * u);
+                        segment.clear();
+                    }
+                }
+            }
+            canvas.polyline(&segment, *color, width * u);
+        }
+
+        let ticks = count.min(6);
+        for t in 0..ticks {
+            let i = if ticks == 1 {
+                0
+            } else {
+                t * (count - 1) / (ticks - 1)
+            };
+            let label = NaiveDate::parse_from_str(&series.points[i].period_start, "%Y-%m-%d")
+                .map(|d| d.format("%d/%m").to_string())
+                .unwrap_or_else(|_| series.points[i].period_start.clone());
+            let anchor = match t {
+                0 => Anchor::Start,
+                _ if t == ticks - 1 => Anchor::End,
+                _ => Anchor::Middle,
+            };
+            canvas.text(
+                x_of(i),
+                plot.bottom + 18.0 * u,
+                &label,
+                TextStyle::new(11.0 * u, self.theme.muted).anchor(anchor),
+            );
+        }
+
+        // Legenda di kanan atas, disusun dari kanan
+        let mut x = canvas.width - 24.0 * u;
+        let y = 24.0 * u + 12.0 * u;
+        for (_, color, width, key) in lines.iter().rev() {
+            let label = text(self.locale, key);
+            canvas.text(
+                x,
+                y + 4.0 * u,
+                &label,
+                TextStyle::new(11.0 * u, self.theme.muted).anchor(Anchor::End),
+            );
+            x -= label.chars().count() as f32 * 5.8 * u + 6.0 * u;
+            canvas.line((x - 16.0 * u, y), (x, y), *color, width * u);
+            x -= 28.0 * u;
+        }
+        canvas.finish()
+    }
+
+    pub fn category_pie(&self, stats: &MonthlyStats, activity: &MonthActivity) -> String {
+        let mut canvas = self.canvas();
+        let u = canvas.unit();
+        let title = format!(
+            "{} · {} {}",
+            text(self.locale, "report.categories"),
+            stats.month,
+            stats.year
+        );
+        let plot = self.frame(&mut canvas, &title);
+
+        let mut slices: Vec<(&String, usize)> = activity
+            .categories
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(category, count)| (category, *count))
+            .collect();
+        slices.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        let total: usize = slices.iter().map(|(_, count)| count).sum();
+        if total == 0 {
+            self.empty(&mut canvas, &plot);
+            return canvas.finish();
+        }
+
+        let radius = (plot.height() + 24.0 * u).min(plot.width() * 0.5) / 2.0;
+        let center = (
+            plot.left - 36.0 * u + radius,
+            plot.top + plot.height() / 2.0,
+        );
+        let mut angle = 0.0f32;
+        for (category, count) in &slices {
+            let sweep = *count as f32 / total as f32 * 2.0 * PI;
+            let color = category_color(category);
+            if *count == total {
+                canvas.circle(center.0, center.1, radius, color);
+            } else {
+                let (x1, y1) = polar(center, radius, angle);
+                let (x2, y2) = polar(center, radius, angle + sweep);
+                let large = if sweep > PI { 1 } else { 0 };
+                canvas.path(
+                    &format!(
+                        "M {:.2} {:.2} L {:.2} {:.2} A {:.2} {:.2} 0 {} 1 {:.2} {:.2} Z",
+                        center.0, center.1, x1, y1, radius, radius, large, x2, y2
+                    ),
+                    color,
+                );
+            }
+            angle += sweep;
+        }
+
+        let legend_x = center.0 + radius + 40.0 * u;
+        let row = (22.0 * u).min(plot.height() / slices.len() as f32);
+        let legend_top = center.1 - row * slices.len() as f32 / 2.0;
+        for (i, (category, count)) in slices.iter().enumerate() {
+            let y = legend_top + row * i as f32;
+            canvas.rect(
+                legend_x,
+                y + 3.0 * u,
+                12.0 * u,
+                12.0 * u,
+                category_color(category),
+                3.0 * u,
+            );
+            canvas.text(
+                legend_x + 20.0 * u,
+                y + 13.5 * u,
+                &text(self.locale, &format!("category.{}", category)),
+                TextStyle::new(13.0 * u, self.theme.text),
+            );
+            canvas.text(
+                canvas.width - 24.0 * u,
+                y + 13.5 * u,
+                &format!(
+                    "{} · {}",
+                    format_number(self.locale, *count as f64, 0),
+                    format_percent(self.locale, *count as f64 / total as f64 * 100.0, 0)
+                ),
+                TextStyle::new(13.0 * u, self.theme.muted).anchor(Anchor::End),
+            );
+        }
+        canvas.finish()
+    }
+
+    // Kartu ringkas untuk dibagikan: streak di tengah cincin konsistensi bulan ini
+    pub fn share_card(&self, stats: &MonthlyStats, streak: usize) -> String {
+        let mut canvas = self.canvas();
+        let u = canvas.unit();
+        let pad = 24.0 * u;
+        let theme = self.theme;
+
+        canvas.rect(0.0, 0.0, canvas.width, 8.0 * u, theme.primary, 0.0);
+        canvas.text(
+            pad,
+            pad + 18.0 * u,
+            "Habit Tracker",
+            TextStyle::new(18.0 * u, theme.text).bold(),
+        );
+        canvas.text(
+            canvas.width - pad,
+            pad + 18.0 * u,
+            &format!("{} {}", stats.month, stats.year),
+            TextStyle::new(14.0 * u, theme.muted).anchor(Anchor::End),
+        );
+
+        let min = canvas.width.min(canvas.height);
+        let radius = min * 0.26;
+        let stroke = 16.0 * u;
+        let center = (canvas.width / 2.0, canvas.height * 0.47);
+        canvas.arc(center, radius, 0.0, PI, theme.empty, stroke);
+        canvas.arc(center, radius, PI, 2.0 * PI, theme.empty, stroke);
+        let progress = (stats.consistency_percentage / 100.0).clamp(0.0, 1.0) as f32;
+        if progress >= 0.999 {
+            canvas.arc(center, radius, 0.0, PI, theme.primary, stroke);
+            canvas.arc(center, radius, PI, 2.0 * PI, theme.primary, stroke);
+        } else if progress > 0.0 {
+            canvas.arc(
+                center,
+                radius,
+                0.0,
+                progress * 2.0 * PI,
+                theme.primary,
+                stroke,
+            );
+        }
+
+        canvas.text(
+            center.0,
+            center.1 + 22.0 * u,
+            &format_number(self.locale, streak as f64, 0),
+            TextStyle::new(radius * 0.55, theme.text)
+                .anchor(Anchor::Middle)
+                .bold(),
+        );
+        canvas.text(
+            center.0,
+            center.1 + radius * 0.42,
+            &text(self.locale, "chart.streak"),
+            TextStyle::new(14.0 * u, theme.muted).anchor(Anchor::Middle),
+        );
+
+        let category = match stats.most_common_category.as_str() {
+            "none" => "–".to_string(),
+            name => text(self.locale, &format!("category.{}", name)),
+        };
+        let blocks = [
+            (
+                text(self.locale, "report.consistency"),
+                format_percent(self.locale, stats.consistency_percentage, 0),
+            ),
+            (
+                text(self.locale, "report.total_entries"),
+                format_number(self.locale, stats.total_habits as f64, 0),
+            ),
+            (text(self.locale, "report.most_common_category"), category),
+        ];
+        let slot = (canvas.width - 2.0 * pad) / blocks.len() as f32;
+        for (i, (label, value)) in blocks.iter().enumerate() {
+            let x = pad + slot * (i as f32 + 0.5);
+            canvas.text(
+                x,
+                canvas.height - pad - 20.0 * u,
+                value,
+                TextStyle::new(22.0 * u, theme.text)
+                    .anchor(Anchor::Middle)
+                    .bold(),
+            );
+            canvas.text(
+                x,
+                canvas.height - pad,
+                label,
+                TextStyle::new(11.0 * u, theme.muted).anchor(Anchor::Middle),
+            );
+        }
+        canvas.finish()
+    }
+}
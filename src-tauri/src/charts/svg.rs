@@ -0,0 +1,212 @@
+use std::fmt::Write;
+
+use crate::charts::{hex, ChartTheme, FONT_FAMILY};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Anchor {
+    Start,
+    Middle,
+    End,
+}
+
+impl Anchor {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Anchor::Start => "start",
+            Anchor::Middle => "middle",
+            Anchor::End => "end",
+        }
+    }
+}
+
+pub fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TextStyle {
+    pub size: f32,
+    pub fill: u32,
+    pub anchor: Anchor,
+    pub bold: bool,
+}
+
+impl TextStyle {
+    pub fn new(size: f32, fill: u32) -> Self {
+        Self {
+            size,
+            fill,
+            anchor: Anchor::Start,
+            bold: false,
+        }
+    }
+
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+}
+
+// Koordinat dalam piksel dengan titik asal di kiri atas
+pub struct SvgCanvas {
+    pub width: f32,
+    pub height: f32,
+    pub theme: ChartTheme,
+    body: String,
+}
+
+impl SvgCanvas {
+    pub fn new(width: u32, height: u32, theme: ChartTheme) -> Self {
+        let mut canvas = Self {
+            width: width as f32,
+            height: height as f32,
+            theme,
+            body: String::new(),
+        };
+        canvas.rect(0.0, 0.0, canvas.width, canvas.height, theme.background, 0.0);
+        canvas
+    }
+
+    // Satuan relatif terhadap ukuran Medium (800x450), supaya semua preset proporsional
+    pub fn unit(&self) -> f32 {
+        self.width.min(self.height) / 450.0
+    }
+
+    pub fn rect(&mut self, x: f32, y: f32, width: f32, height: f32, fill: u32, radius: f32) {
+        let _ = writeln!(
+            self.body,
+            r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" rx="{:.1}" fill="{}"/>"#,
+            x,
+            y,
+            width.max(0.0),
+            height.max(0.0),
+            radius,
+            hex(fill)
+        );
+    }
+
+    pub fn circle(&mut self, cx: f32, cy: f32, r: f32, fill: u32) {
+        let _ = writeln!(
+            self.body,
+            r#"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="{}"/>"#,
+            cx,
+            cy,
+            r,
+            hex(fill)
+        );
+    }
+
+    pub fn line(&mut self, from: (f32, f32), to: (f32, f32), stroke: u32, width: f32) {
+        let _ = writeln!(
+            self.body,
+            r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="{:.1}"/>"#,
+            from.0,
+            from.1,
+            to.0,
+            to.1,
+            hex(stroke),
+            width
+        );
+    }
+
+    pub fn polyline(&mut self, points: &[(f32, f32)], stroke: u32, width: f32) {
+        if points.len() < 2 {
+            if let Some((x, y)) = points.first() {
+                self.circle(*x, *y, width, stroke);
+            }
+            return;
+        }
+        let points: Vec<String> = points
+            .iter()
+            .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+            .collect();
+        let _ = writeln!(
+            self.body,
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{:.1}" stroke-linejoin="round" stroke-linecap="round"/>"#,
+            points.join(" "),
+            hex(stroke),
+            width
+        );
+    }
+
+    pub fn path(&mut self, d: &str, fill: u32) {
+        let _ = writeln!(self.body, r#"<path d="{}" fill="{}"/>"#, d, hex(fill));
+    }
+
+    // Busur terbuka, sudut dalam radian dari arah jam 12 searah jarum jam
+    pub fn arc(
+        &mut self,
+        center: (f32, f32),
+        r: f32,
+        start: f32,
+        end: f32,
+        stroke: u32,
+        width: f32,
+    ) {
+        let (x1, y1) = polar(center, r, start);
+        let (x2, y2) = polar(center, r, end);
+        let large = if end - start > std::f32::consts::PI {
+            1
+        } else {
+            0
+        };
+        let _ = writeln!(
+            self.body,
+            r#"<path d="M {:.2} {:.2} A {:.2} {:.2} 0 {} 1 {:.2} {:.2}" fill="none" stroke="{}" stroke-width="{:.1}" stroke-linecap="round"/>"#,
+            x1,
+            y1,
+            r,
+            r,
+            large,
+            x2,
+            y2,
+            hex(stroke),
+            width
+        );
+    }
+
+    pub fn text(&mut self, x: f32, y: f32, value: &str, style: TextStyle) {
+        let _ = writeln!(
+            self.body,
+            r#"<text x="{:.1}" y="{:.1}" font-size="{:.1}" fill="{}" text-anchor="{}"{}>{}</text>"#,
+            x,
+            y,
+            style.size,
+            hex(style.fill),
+            style.anchor.as_str(),
+            if style.bold {
+                r#" font-weight="bold""#
+            } else {
+                ""
+            },
+            escape_xml(value)
+        );
+    }
+
+    pub fn finish(self) -> String {
+        format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" "#,
+                r#"font-family="'{font}', sans-serif">"#,
+                "\n{body}</svg>\n"
+            ),
+            w = self.width,
+            h = self.height,
+            font = FONT_FAMILY,
+            body = self.body
+        )
+    }
+}
+
+pub fn polar(center: (f32, f32), r: f32, angle: f32) -> (f32, f32) {
+    (center.0 + r * angle.sin(), center.1 - r * angle.cos())
+}
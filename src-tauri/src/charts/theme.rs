@@ -0,0 +1,91 @@
+use crate::models::{Category, ChartThemeName};
+
+// Font ikut dibundel supaya grafik dan PDF bisa dibuat tanpa koneksi dan tanpa font sistem
+pub const FONT_REGULAR: &[u8] = include_bytes!("../../fonts/DejaVuSans.ttf");
+pub const FONT_BOLD: &[u8] = include_bytes!("../../fonts/DejaVuSans-Bold.ttf");
+pub const FONT_FAMILY: &str = "DejaVu Sans";
+
+// Warna dalam 0xRRGGBB, versi terang sama dengan tema frontend (assets/styles.css)
+#[derive(Debug, Clone, Copy)]
+pub struct ChartTheme {
+    pub background: u32,
+    pub surface: u32,
+    pub text: u32,
+    pub muted: u32,
+    pub border: u32,
+    pub empty: u32,
+    pub primary: u32,
+    pub primary_light: u32,
+    pub accent: u32,
+    // Garis nilai harian di belakang rata-rata bergulir
+    pub baseline: u32,
+}
+
+impl ChartTheme {
+    pub const LIGHT: ChartTheme = ChartTheme {
+        background: 0xffffff,
+        surface: 0xf8fafc,
+        text: 0x1e293b,
+        muted: 0x64748b,
+        border: 0xe2e8f0,
+        empty: 0xf1f5f9,
+        primary: 0x4f46e5,
+        primary_light: 0xe0e7ff,
+        accent: 0xf59e0b,
+        baseline: 0xcbd5e1,
+    };
+
+    pub const DARK: ChartTheme = ChartTheme {
+        background: 0x0f172a,
+        surface: 0x1e293b,
+        text: 0xf1f5f9,
+        muted: 0x94a3b8,
+        border: 0x334155,
+        empty: 0x1e293b,
+        primary: 0x818cf8,
+        primary_light: 0x312e81,
+        accent: 0xfbbf24,
+        baseline: 0x475569,
+    };
+
+    pub fn from_name(name: ChartThemeName) -> Self {
+        match name {
+            ChartThemeName::Light => Self::LIGHT,
+            ChartThemeName::Dark => Self::DARK,
+        }
+    }
+
+    // Intensitas 0.0-1.0, dipakai heatmap
+    pub fn scale(&self, t: f32) -> u32 {
+        if t <= 0.0 {
+            self.empty
+        } else {
+            mix(self.primary_light, self.primary, t)
+        }
+    }
+}
+
+pub fn category_color(category: &str) -> u32 {
+    match Category::from_str(category) {
+        Category::Health => 0x22c55e,
+        Category::Productivity => 0x3b82f6,
+        Category::Hygiene => 0xf59e0b,
+        Category::Exercise => 0xec4899,
+        Category::Sleep => 0x6366f1,
+        Category::Other => 0x94a3b8,
+    }
+}
+
+// Campuran linear dua warna, t = 0 -> from, t = 1 -> to
+pub fn mix(from: u32, to: u32, t: f32) -> u32 {
+    let t = t.clamp(0.0, 1.0);
+    [16, 8, 0].iter().fold(0, |acc, shift| {
+        let a = ((from >> shift) & 0xff) as f32;
+        let b = ((to >> shift) & 0xff) as f32;
+        acc | (((a + (b - a) * t).round() as u32) << shift)
+    })
+}
+
+pub fn hex(color: u32) -> String {
+    format!("#{:06x}", color & 0xffffff)
+}
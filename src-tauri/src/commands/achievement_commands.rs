@@ -0,0 +1,85 @@
+use chrono::{Local, Utc};
+use tauri::{AppHandle, Emitter, State};
+
+use crate::commands::{resolve_locale, AppState};
+use crate::database::HabitDatabase;
+use crate::models::Achievement;
+use crate::statistics::{AchievementEngine, ACHIEVEMENTS};
+
+pub const ACHIEVEMENT_UNLOCKED_EVENT: &str = "achievement-unlocked";
+pub const EVALUATION_FAILED_EVENT: &str = "evaluation-failed";
+
+// Entry sudah tersimpan, jadi kegagalan evaluasi setelahnya hanya dilaporkan ke frontend
+pub fn report_evaluation_error(app: &AppHandle, error: String) {
+    let _ = app.emit(EVALUATION_FAILED_EVENT, error);
+}
+
+// Dipanggil setelah add_habit, achievement baru disimpan lalu dikirim ke frontend
+pub fn unlock_new_achievements(
+    app: &AppHandle,
+    db: &HabitDatabase,
+) -> Result<Vec<Achievement>, String> {
+    let habits = db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let already_unlocked = db
+        .get_unlocked_achievements()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let locale = db
+        .get_locale()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let now = Utc::now();
+    let mut newly_unlocked = Vec::new();
+
+    for definition in AchievementEngine::evaluate(&habits, Local::now().date_naive()) {
+        if already_unlocked.contains_key(definition.id) {
+            continue;
+        }
+
+        let inserted = db
+            .unlock_achievement(definition.id, now)
+            .map_err(|e| format!("Database error: {}", e))?;
+        if !inserted {
+            continue;
+        }
+
+        let achievement = Achievement {
+            id: definition.id.to_string(),
+            title: definition.title(locale),
+            description: definition.description(locale),
+            unlocked: true,
+            unlocked_at: Some(now),
+        };
+        let _ = app.emit(ACHIEVEMENT_UNLOCKED_EVENT, achievement.clone());
+        newly_unlocked.push(achievement);
+    }
+
+    Ok(newly_unlocked)
+}
+
+#[tauri::command]
+pub fn get_achievements(
+    locale: Option<String>,
+    state: State<AppState>,
+) -> Result<Vec<Achievement>, String> {
+    let locale = resolve_locale(locale, &state)?;
+    let unlocked = state
+        .db
+        .get_unlocked_achievements()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(ACHIEVEMENTS
+        .iter()
+        .map(|definition| {
+            let unlocked_at = unlocked.get(definition.id).copied();
+            Achievement {
+                id: definition.id.to_string(),
+                title: definition.title(locale),
+                description: definition.description(locale),
+                unlocked: unlocked_at.is_some(),
+                unlocked_at,
+            }
+        })
+        .collect())
+}
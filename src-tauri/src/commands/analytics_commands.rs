@@ -0,0 +1,205 @@
+use chrono::{Duration, Local, NaiveDate};
+use tauri::State;
+
+use crate::commands::{resolve_locale, AppState};
+use crate::models::{
+    AnomalyEvent, AtRiskReport, ComplianceReport, CorrelationReport, Habit, SeriesFilter,
+    SeriesReport, TimeGrouping, TimeOfDayStats, TrendAnalysis, TrendMetric,
+};
+use crate::statistics::{
+    CompletionPredictor, CorrelationAnalyzer, SeriesBuilder, StatisticsCalculator, TrendAnalyzer,
+    DEFAULT_FINDING_LIMIT, DEFAULT_MIN_SAMPLES, MAX_CORRELATION_DAYS,
+};
+
+pub fn parse_date_or(date: Option<String>, default: NaiveDate) -> Result<NaiveDate, String> {
+    match date {
+        Some(date) => NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+            .map_err(|e| format!("Invalid date format: {}", e)),
+        None => Ok(default),
+    }
+}
+
+#[tauri::command]
+pub fn get_habit_correlations(
+    start: Option<String>,
+    end: Option<String>,
+    min_samples: Option<usize>,
+    limit: Option<usize>,
+    state: State<AppState>,
+) -> Result<CorrelationReport, String> {
+    let (default_start, default_end) =
+        CorrelationAnalyzer::default_range(Local::now().date_naive());
+    let start_date = parse_date_or(start, default_start)?;
+    let end_date = parse_date_or(end, default_end)?;
+    if end_date < start_date {
+        return Err("End date must not be before start date".to_string());
+    }
+    if (end_date - start_date).num_days() >= MAX_CORRELATION_DAYS {
+        return Err(format!(
+            "Date range must not exceed {} days",
+            MAX_CORRELATION_DAYS
+        ));
+    }
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(CorrelationAnalyzer::analyze(
+        &habits,
+        start_date,
+        end_date,
+        min_samples.unwrap_or(DEFAULT_MIN_SAMPLES),
+        limit.unwrap_or(DEFAULT_FINDING_LIMIT),
+    ))
+}
+
+#[tauri::command]
+pub fn get_trend(
+    metric: TrendMetric,
+    start: Option<String>,
+    end: Option<String>,
+    habit_name: Option<String>,
+    state: State<AppState>,
+) -> Result<TrendAnalysis, String> {
+    let today = Local::now().date_naive();
+    let start_date = parse_date_or(start, today - Duration::days(27))?;
+    let end_date = parse_date_or(end, today)?;
+
+    let habits: Vec<Habit> = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?
+        .into_iter()
+        .filter(|h| habit_name.as_ref().is_none_or(|name| &h.name == name))
+        .collect();
+
+    let series = TrendAnalyzer::daily_series(&habits, start_date, end_date, metric);
+    Ok(TrendAnalyzer::analyze(&series))
+}
+
+#[tauri::command]
+pub fn get_compliance_report(
+    start: Option<String>,
+    end: Option<String>,
+    state: State<AppState>,
+) -> Result<ComplianceReport, String> {
+    let today = Local::now().date_naive();
+    let start_date = parse_date_or(start, today - Duration::days(29))?;
+    let end_date = parse_date_or(end, today)?;
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let scales = state
+        .db
+        .get_habit_scales()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(StatisticsCalculator::calculate_compliance_report(
+        &habits, &scales, start_date, end_date,
+    ))
+}
+
+#[tauri::command]
+pub fn get_time_of_day_stats(
+    start: Option<String>,
+    end: Option<String>,
+    habit_name: Option<String>,
+    state: State<AppState>,
+) -> Result<Vec<TimeOfDayStats>, String> {
+    let today = Local::now().date_naive();
+    let start_date = parse_date_or(start, today - Duration::days(89))?;
+    let end_date = parse_date_or(end, today)?;
+
+    let habits: Vec<Habit> = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?
+        .into_iter()
+        .filter(|h| habit_name.as_ref().is_none_or(|name| &h.name == name))
+        .collect();
+
+    Ok(StatisticsCalculator::calculate_time_of_day_stats(
+        &habits, start_date, end_date,
+    ))
+}
+
+#[tauri::command]
+pub fn get_at_risk_habits(
+    locale: Option<String>,
+    state: State<AppState>,
+) -> Result<AtRiskReport, String> {
+    let locale = resolve_locale(locale, &state)?;
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(CompletionPredictor::at_risk_report(
+        &habits,
+        Local::now().date_naive(),
+        locale,
+    ))
+}
+
+#[tauri::command]
+pub fn get_anomalies(
+    start: Option<String>,
+    end: Option<String>,
+    state: State<AppState>,
+) -> Result<Vec<AnomalyEvent>, String> {
+    let today = Local::now().date_naive();
+    let start_date = parse_date_or(start, today - Duration::days(29))?;
+    let end_date = parse_date_or(end, today)?;
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(StatisticsCalculator::detect_anomalies(
+        &habits, start_date, end_date, today,
+    ))
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn get_series(
+    metric: TrendMetric,
+    start: Option<String>,
+    end: Option<String>,
+    grouping: Option<TimeGrouping>,
+    habit_name: Option<String>,
+    category: Option<String>,
+    tag: Option<String>,
+    state: State<AppState>,
+) -> Result<SeriesReport, String> {
+    let today = Local::now().date_naive();
+    let start_date = parse_date_or(start, today - Duration::days(89))?;
+    let end_date = parse_date_or(end, today)?;
+    if end_date < start_date {
+        return Err("End date must not be before start date".to_string());
+    }
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(SeriesBuilder::build(
+        &habits,
+        metric,
+        start_date,
+        end_date,
+        grouping.unwrap_or(TimeGrouping::Day),
+        SeriesFilter {
+            habit_name,
+            category,
+            tag,
+        },
+        today,
+    ))
+}
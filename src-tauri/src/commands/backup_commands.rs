@@ -0,0 +1,102 @@
+use chrono::Utc;
+use std::collections::HashMap;
+use std::fs;
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::models::{
+    BackupDocument, BackupExportResult, ImportMode, ImportSummary, RatingScale, BACKUP_FORMAT,
+    BACKUP_FORMAT_VERSION,
+};
+use crate::transfer::validate_compliance;
+
+fn read_backup_document(path: &str) -> Result<BackupDocument, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("File error: {}", e))?;
+    let document: BackupDocument =
+        serde_json::from_str(&contents).map_err(|e| format!("Invalid backup file: {}", e))?;
+
+    if document.format != BACKUP_FORMAT {
+        return Err(format!("Unknown backup format: {}", document.format));
+    }
+    if document.format_version > BACKUP_FORMAT_VERSION {
+        return Err(format!(
+            "Backup format version {} is newer than supported version {}",
+            document.format_version, BACKUP_FORMAT_VERSION
+        ));
+    }
+    Ok(document)
+}
+
+#[tauri::command]
+pub fn export_backup(path: String, state: State<AppState>) -> Result<BackupExportResult, String> {
+    let data = state
+        .db
+        .export_backup_data()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let document = BackupDocument {
+        format: BACKUP_FORMAT.to_string(),
+        format_version: BACKUP_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Utc::now(),
+        data,
+    };
+    let json = serde_json::to_string_pretty(&document)
+        .map_err(|e| format!("Serialization error: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("File error: {}", e))?;
+
+    Ok(BackupExportResult {
+        path,
+        format_version: document.format_version,
+        exported_at: document.exported_at,
+        habit_count: document.data.habits.len(),
+        goal_count: document.data.goals.len(),
+    })
+}
+
+// Default-nya dry run; frontend menampilkan ringkasan dulu lalu memanggil ulang dengan dry_run = false
+#[tauri::command]
+pub fn import_backup(
+    path: String,
+    mode: Option<ImportMode>,
+    dry_run: Option<bool>,
+    state: State<AppState>,
+) -> Result<ImportSummary, String> {
+    let document = read_backup_document(&path)?;
+
+    if let Some(habit) = document
+        .data
+        .habits
+        .iter()
+        .find(|h| h.name.trim().is_empty() || h.quantity.is_some_and(|q| q < 0.0))
+    {
+        return Err(format!("Invalid habit entry in backup: {}", habit.id));
+    }
+
+    // Skala yang berlaku setelah import: mode replace memakai skala dari backup saja,
+    // mode merge mempertahankan skala yang sudah ada di database
+    let mode = mode.unwrap_or_default();
+    let mut scales: HashMap<String, RatingScale> = document
+        .data
+        .habit_scales
+        .iter()
+        .map(|(name, scale)| (name.clone(), scale.clone()))
+        .collect();
+    if mode == ImportMode::Merge {
+        scales.extend(
+            state
+                .db
+                .get_habit_scales()
+                .map_err(|e| format!("Database error: {}", e))?,
+        );
+    }
+    for habit in &document.data.habits {
+        validate_compliance(habit, &scales)
+            .map_err(|e| format!("Invalid habit entry in backup: {}: {}", habit.id, e))?;
+    }
+
+    state
+        .db
+        .import_backup(&document, mode, dry_run.unwrap_or(true))
+        .map_err(|e| format!("Database error: {}", e))
+}
@@ -0,0 +1,79 @@
+use chrono::{Duration, Local};
+use tauri::State;
+
+use crate::commands::{parse_date_or, resolve_locale, AppState};
+use crate::models::{BalanceReport, BalanceTarget, Category};
+use crate::statistics::{StatisticsCalculator, DEFAULT_NEGLECT_DAYS};
+
+#[tauri::command]
+pub fn get_balance_report(
+    start: Option<String>,
+    end: Option<String>,
+    neglect_days: Option<i64>,
+    locale: Option<String>,
+    state: State<AppState>,
+) -> Result<BalanceReport, String> {
+    let today = Local::now().date_naive();
+    let start_date = parse_date_or(start, today - Duration::days(29))?;
+    let end_date = parse_date_or(end, today)?;
+    let locale = resolve_locale(locale, &state)?;
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let weights = state
+        .db
+        .get_balance_targets()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(StatisticsCalculator::calculate_balance_report(
+        &habits,
+        &weights,
+        start_date,
+        end_date,
+        today,
+        neglect_days.unwrap_or(DEFAULT_NEGLECT_DAYS).max(1),
+        locale,
+    ))
+}
+
+#[tauri::command]
+pub fn get_balance_targets(state: State<AppState>) -> Result<Vec<BalanceTarget>, String> {
+    let weights = state
+        .db
+        .get_balance_targets()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(StatisticsCalculator::balance_targets(&weights))
+}
+
+#[tauri::command]
+pub fn set_balance_target(
+    target: BalanceTarget,
+    state: State<AppState>,
+) -> Result<BalanceTarget, String> {
+    if !Category::ALL.iter().any(|c| c.as_str() == target.category) {
+        return Err(format!("Unknown category: {}", target.category));
+    }
+    if !target.weight.is_finite() || target.weight < 0.0 {
+        return Err("Weight must be zero or a positive number".to_string());
+    }
+
+    state
+        .db
+        .set_balance_target(&target.category, target.weight)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(target)
+}
+
+#[tauri::command]
+pub fn reset_balance_targets(state: State<AppState>) -> Result<Vec<BalanceTarget>, String> {
+    state
+        .db
+        .reset_balance_targets()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(StatisticsCalculator::balance_targets(&Default::default()))
+}
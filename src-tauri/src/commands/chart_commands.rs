@@ -0,0 +1,112 @@
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use std::fs;
+use tauri::State;
+
+use crate::charts::{svg_to_png, ChartRenderer, ChartTheme, MonthActivity};
+use crate::commands::{parse_date_or, resolve_locale, AppState};
+use crate::models::{
+    ChartExportResult, ChartFormat, ChartKind, ChartOptions, TimeGrouping, TrendMetric,
+};
+use crate::statistics::{SeriesBuilder, StatisticsCalculator};
+
+// Render grafik sesuai opsi, mengembalikan SVG beserta ukurannya
+fn render_svg(options: ChartOptions, state: &AppState) -> Result<(String, u32, u32), String> {
+    let locale = resolve_locale(options.locale.clone(), state)?;
+    let (width, height) = options
+        .size
+        .unwrap_or(options.kind.default_size())
+        .dimensions();
+    let renderer = ChartRenderer {
+        width,
+        height,
+        theme: ChartTheme::from_name(options.theme),
+        locale,
+    };
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let today = Local::now().date_naive();
+
+    let svg = match options.kind {
+        ChartKind::WeeklyBars => {
+            let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+            let week_start = parse_date_or(options.week_start, monday)?;
+            renderer.weekly_bars(&StatisticsCalculator::calculate_weekly_stats(
+                &habits, week_start,
+            ))
+        }
+        ChartKind::StrengthLine => {
+            let start = parse_date_or(options.start_date, today - Duration::days(89))?;
+            let end = parse_date_or(options.end_date, today)?;
+            if end < start {
+                return Err("End date must not be before start date".to_string());
+            }
+            renderer.strength_line(&SeriesBuilder::build(
+                &habits,
+                TrendMetric::DailyCount,
+                start,
+                end,
+                TimeGrouping::Day,
+                options.filter,
+                today,
+            ))
+        }
+        ChartKind::MonthlyHeatmap | ChartKind::CategoryPie | ChartKind::ShareCard => {
+            let year = options.year.unwrap_or(today.year());
+            let month = options.month.unwrap_or(today.month());
+            if NaiveDate::from_ymd_opt(year, month, 1).is_none() {
+                return Err(format!("Invalid month: {}-{}", year, month));
+            }
+            let preferences = state
+                .db
+                .get_insight_preferences()
+                .map_err(|e| format!("Database error: {}", e))?;
+            let stats = StatisticsCalculator::calculate_monthly_stats(
+                &habits,
+                year,
+                month,
+                &preferences,
+                today,
+                locale,
+            );
+            let activity = MonthActivity::from_stats(&stats, month)
+                .ok_or_else(|| format!("Invalid month: {}-{}", year, month))?;
+
+            match options.kind {
+                ChartKind::MonthlyHeatmap => renderer.monthly_heatmap(&stats, &activity),
+                ChartKind::CategoryPie => renderer.category_pie(&stats, &activity),
+                _ => renderer.share_card(&stats, StatisticsCalculator::get_current_streak(&habits)),
+            }
+        }
+    };
+    Ok((svg, width, height))
+}
+
+#[tauri::command]
+pub fn render_chart(options: ChartOptions, state: State<AppState>) -> Result<String, String> {
+    render_svg(options, &state).map(|(svg, _, _)| svg)
+}
+
+#[tauri::command]
+pub fn export_chart(
+    path: String,
+    options: ChartOptions,
+    state: State<AppState>,
+) -> Result<ChartExportResult, String> {
+    let format = options.format;
+    let (svg, width, height) = render_svg(options, &state)?;
+    let bytes = match format {
+        ChartFormat::Svg => svg.into_bytes(),
+        ChartFormat::Png => svg_to_png(&svg)?,
+    };
+    fs::write(&path, bytes).map_err(|e| format!("File error: {}", e))?;
+
+    Ok(ChartExportResult {
+        path,
+        format,
+        width,
+        height,
+    })
+}
@@ -0,0 +1,140 @@
+use chrono::{Local, NaiveDate, Utc};
+use tauri::State;
+use uuid::Uuid;
+
+use crate::commands::AppState;
+use crate::models::{Goal, GoalHistory, GoalInput, GoalPeriod, GoalProgress};
+use crate::statistics::GoalTracker;
+
+fn validate_goal_input(input: &GoalInput) -> Result<(), String> {
+    if input.title.trim().is_empty() {
+        return Err("Goal title cannot be empty".to_string());
+    }
+    if input.target <= 0.0 {
+        return Err("Goal target must be greater than zero".to_string());
+    }
+    if input.scope.parts().1.trim().is_empty() {
+        return Err("Goal scope cannot be empty".to_string());
+    }
+
+    if input.period == GoalPeriod::Custom {
+        let parse = |date: &Option<String>, label: &str| {
+            date.as_ref()
+                .ok_or_else(|| format!("Custom goal needs a {} date", label))
+                .and_then(|d| {
+                    NaiveDate::parse_from_str(d, "%Y-%m-%d")
+                        .map_err(|e| format!("Invalid {} date: {}", label, e))
+                })
+        };
+        let start = parse(&input.start_date, "start")?;
+        let end = parse(&input.end_date, "end")?;
+        if end < start {
+            return Err("Goal end date must not be before its start date".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+fn goal_from_input(id: String, created_at: chrono::DateTime<Utc>, input: GoalInput) -> Goal {
+    let custom = input.period == GoalPeriod::Custom;
+    Goal {
+        id,
+        title: input.title.trim().to_string(),
+        scope: input.scope,
+        metric: input.metric,
+        target: input.target,
+        period: input.period,
+        start_date: if custom { input.start_date } else { None },
+        end_date: if custom { input.end_date } else { None },
+        created_at,
+        archived: input.archived,
+    }
+}
+
+fn load_goal(id: &str, state: &State<AppState>) -> Result<Goal, String> {
+    state
+        .db
+        .get_goal(id)
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Goal not found: {}", id))
+}
+
+#[tauri::command]
+pub fn add_goal(input: GoalInput, state: State<AppState>) -> Result<Goal, String> {
+    validate_goal_input(&input)?;
+
+    let goal = goal_from_input(Uuid::new_v4().to_string(), Utc::now(), input);
+
+    state
+        .db
+        .upsert_goal(&goal)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(goal)
+}
+
+#[tauri::command]
+pub fn update_goal(id: String, input: GoalInput, state: State<AppState>) -> Result<Goal, String> {
+    validate_goal_input(&input)?;
+
+    let existing = load_goal(&id, &state)?;
+    let goal = goal_from_input(existing.id, existing.created_at, input);
+
+    state
+        .db
+        .upsert_goal(&goal)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(goal)
+}
+
+#[tauri::command]
+pub fn get_goals(state: State<AppState>) -> Result<Vec<Goal>, String> {
+    state
+        .db
+        .get_goals()
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[tauri::command]
+pub fn delete_goal(id: String, state: State<AppState>) -> Result<bool, String> {
+    state
+        .db
+        .delete_goal(&id)
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[tauri::command]
+pub fn get_goal_progress(state: State<AppState>) -> Result<Vec<GoalProgress>, String> {
+    let goals = state
+        .db
+        .get_goals()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let today = Local::now().date_naive();
+    Ok(goals
+        .iter()
+        .filter(|g| !g.archived)
+        .filter_map(|g| GoalTracker::progress(g, &habits, today))
+        .collect())
+}
+
+#[tauri::command]
+pub fn get_goal_history(id: String, state: State<AppState>) -> Result<GoalHistory, String> {
+    let goal = load_goal(&id, &state)?;
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(GoalTracker::history(
+        &goal,
+        &habits,
+        Local::now().date_naive(),
+    ))
+}
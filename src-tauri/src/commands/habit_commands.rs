@@ -1,9 +1,15 @@
 use chrono::{Datelike, Duration, Local, NaiveDate, Utc};
-use tauri::State;
+use tauri::{AppHandle, State};
 use uuid::Uuid;
 
+use crate::commands::{
+    announce_new_records, report_evaluation_error, resolve_locale, unlock_new_achievements,
+};
 use crate::database::HabitDatabase;
-use crate::models::{Category, DailyStats, Habit, HabitInput, MonthlyStats, WeeklyStats};
+use crate::models::{
+    normalize_tags, Category, DailyStats, Habit, HabitInput, HabitScale, MonthlyStats,
+    RatingScale, WeeklyStats,
+};
 use crate::statistics::StatisticsCalculator;
 
 pub struct AppState {
@@ -12,7 +18,11 @@ pub struct AppState {
 
 // MODIFIED: Fixed default timestamp menggunakan Local timezone
 #[tauri::command]
-pub fn add_habit(input: HabitInput, state: State<AppState>) -> Result<Habit, String> {
+pub fn add_habit(
+    input: HabitInput,
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<Habit, String> {
     let timestamp = input
         .timestamp
         .as_ref()
@@ -20,6 +30,34 @@ pub fn add_habit(input: HabitInput, state: State<AppState>) -> Result<Habit, Str
         .map(|dt| dt.with_timezone(&Utc))
         .unwrap_or_else(|| Local::now().with_timezone(&Utc));
 
+    let end_timestamp = match input.end_timestamp.as_ref() {
+        Some(ts) => {
+            let end = chrono::DateTime::parse_from_rfc3339(ts)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| format!("Invalid end timestamp: {}", e))?;
+            if end <= timestamp {
+                return Err("End timestamp must be after the start timestamp".to_string());
+            }
+            Some(end)
+        }
+        None => None,
+    };
+
+    if input.quantity.is_some_and(|q| !q.is_finite() || q < 0.0) {
+        return Err("Quantity must be zero or a positive number".to_string());
+    }
+
+    // Habit tanpa skala tetap menerima level apa pun seperti sebelum ada skala
+    if let Some(level) = input.compliance_level {
+        let scale = state
+            .db
+            .get_habit_scale(&input.name)
+            .map_err(|e| format!("Database error: {}", e))?;
+        if let Some(scale) = scale {
+            scale.validate(level)?;
+        }
+    }
+
     let habit = Habit {
         id: Uuid::new_v4().to_string(),
         name: input.name,
@@ -27,6 +65,9 @@ pub fn add_habit(input: HabitInput, state: State<AppState>) -> Result<Habit, Str
         timestamp,
         compliance_level: input.compliance_level,
         notes: input.notes,
+        tags: normalize_tags(&input.tags),
+        end_timestamp,
+        quantity: input.quantity,
     };
 
     state
@@ -34,6 +75,14 @@ pub fn add_habit(input: HabitInput, state: State<AppState>) -> Result<Habit, Str
         .insert_habit(&habit)
         .map_err(|e| format!("Database error: {}", e))?;
 
+    // Gagal mengevaluasi achievement tidak boleh membatalkan habit yang sudah tersimpan
+    if let Err(e) = unlock_new_achievements(&app, &state.db) {
+        report_evaluation_error(&app, e);
+    }
+    if let Err(e) = announce_new_records(&app, &state.db, &habit) {
+        report_evaluation_error(&app, e);
+    }
+
     Ok(habit)
 }
 
@@ -114,15 +163,26 @@ pub fn get_weekly_stats(week_start: String, state: State<AppState>) -> Result<We
 pub fn get_monthly_stats(
     year: i32,
     month: u32,
+    locale: Option<String>,
     state: State<AppState>,
 ) -> Result<MonthlyStats, String> {
+    let locale = resolve_locale(locale, &state)?;
     let habits = state
         .db
         .get_all_habits()
         .map_err(|e| format!("Database error: {}", e))?;
+    let preferences = state
+        .db
+        .get_insight_preferences()
+        .map_err(|e| format!("Database error: {}", e))?;
 
     Ok(StatisticsCalculator::calculate_monthly_stats(
-        &habits, year, month,
+        &habits,
+        year,
+        month,
+        &preferences,
+        Local::now().date_naive(),
+        locale,
     ))
 }
 
@@ -206,4 +266,42 @@ pub fn get_categories() -> Vec<String> {
         "sleep".to_string(),
         "other".to_string(),
     ]
-}
\ No newline at end of file
+}
+
+#[tauri::command]
+pub fn set_habit_scale(
+    habit_name: String,
+    scale: RatingScale,
+    state: State<AppState>,
+) -> Result<HabitScale, String> {
+    scale.is_valid()?;
+
+    state
+        .db
+        .set_habit_scale(&habit_name, &scale)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(HabitScale { habit_name, scale })
+}
+
+#[tauri::command]
+pub fn get_habit_scales(state: State<AppState>) -> Result<Vec<HabitScale>, String> {
+    let mut scales: Vec<HabitScale> = state
+        .db
+        .get_habit_scales()
+        .map_err(|e| format!("Database error: {}", e))?
+        .into_iter()
+        .map(|(habit_name, scale)| HabitScale { habit_name, scale })
+        .collect();
+    scales.sort_by(|a, b| a.habit_name.cmp(&b.habit_name));
+
+    Ok(scales)
+}
+
+#[tauri::command]
+pub fn delete_habit_scale(habit_name: String, state: State<AppState>) -> Result<bool, String> {
+    state
+        .db
+        .delete_habit_scale(&habit_name)
+        .map_err(|e| format!("Database error: {}", e))
+}
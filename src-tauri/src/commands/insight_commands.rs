@@ -0,0 +1,99 @@
+use chrono::{Local, NaiveDate};
+use tauri::State;
+
+use crate::commands::{resolve_locale, AppState};
+use crate::models::{Insight, InsightRuleInfo, InsightRuleSettings};
+use crate::statistics::{InsightContext, InsightEngine};
+
+#[tauri::command]
+pub fn get_insights(
+    start: String,
+    end: String,
+    locale: Option<String>,
+    state: State<AppState>,
+) -> Result<Vec<Insight>, String> {
+    let start_date = NaiveDate::parse_from_str(&start, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid start date: {}", e))?;
+    let end_date = NaiveDate::parse_from_str(&end, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid end date: {}", e))?;
+    if end_date < start_date {
+        return Err("End date must not be before start date".to_string());
+    }
+    let locale = resolve_locale(locale, &state)?;
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let preferences = state
+        .db
+        .get_insight_preferences()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let ctx = InsightContext::new(
+        &habits,
+        start_date,
+        end_date,
+        Local::now().date_naive(),
+        locale,
+    )
+    .with_balance_weights(preferences.balance_weights.clone());
+
+    Ok(InsightEngine::default().generate(&ctx, &preferences))
+}
+
+// Dipanggil UI setelah insight benar-benar ditampilkan; getter statistik tidak menulis ke database
+#[tauri::command]
+pub fn mark_insights_shown(insights: Vec<Insight>, state: State<AppState>) -> Result<(), String> {
+    state
+        .db
+        .record_shown_insights(&insights)
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[tauri::command]
+pub fn dismiss_insight(insight_id: String, state: State<AppState>) -> Result<(), String> {
+    state
+        .db
+        .dismiss_insight(&insight_id)
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[tauri::command]
+pub fn restore_insight(insight_id: String, state: State<AppState>) -> Result<bool, String> {
+    state
+        .db
+        .restore_insight(&insight_id)
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[tauri::command]
+pub fn get_insight_rules(
+    locale: Option<String>,
+    state: State<AppState>,
+) -> Result<Vec<InsightRuleInfo>, String> {
+    let locale = resolve_locale(locale, &state)?;
+    let preferences = state
+        .db
+        .get_insight_preferences()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(InsightEngine::default().rules_info(&preferences, locale))
+}
+
+#[tauri::command]
+pub fn update_insight_rule(
+    settings: InsightRuleSettings,
+    state: State<AppState>,
+) -> Result<InsightRuleSettings, String> {
+    if !InsightEngine::default().has_rule(&settings.rule_id) {
+        return Err(format!("Unknown insight rule: {}", settings.rule_id));
+    }
+
+    state
+        .db
+        .set_insight_rule_settings(&settings)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(settings)
+}
@@ -1,3 +1,27 @@
+pub mod achievement_commands;
+pub mod analytics_commands;
+pub mod backup_commands;
+pub mod balance_commands;
+pub mod chart_commands;
+pub mod goal_commands;
 pub mod habit_commands;
+pub mod insight_commands;
+pub mod record_commands;
+pub mod session_commands;
+pub mod settings_commands;
+pub mod sleep_commands;
+pub mod transfer_commands;
 
-pub use habit_commands::*;
\ No newline at end of file
+pub use achievement_commands::*;
+pub use analytics_commands::*;
+pub use backup_commands::*;
+pub use balance_commands::*;
+pub use chart_commands::*;
+pub use goal_commands::*;
+pub use habit_commands::*;
+pub use insight_commands::*;
+pub use record_commands::*;
+pub use session_commands::*;
+pub use settings_commands::*;
+pub use sleep_commands::*;
+pub use transfer_commands::*;
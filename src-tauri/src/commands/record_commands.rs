@@ -0,0 +1,57 @@
+use tauri::{AppHandle, Emitter, State};
+
+use crate::commands::AppState;
+use crate::database::HabitDatabase;
+use crate::models::{Habit, PersonalRecord, SubjectKind};
+use crate::statistics::RecordTracker;
+
+pub const PERSONAL_RECORD_EVENT: &str = "personal-record";
+
+// Dipanggil setelah entry baru tersimpan (add_habit, stop_session, log_sleep);
+// riwayat "sebelum" adalah semua habit kecuali yang baru disimpan
+pub fn announce_new_records(
+    app: &AppHandle,
+    db: &HabitDatabase,
+    habit: &Habit,
+) -> Result<(), String> {
+    let after = db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let before: Vec<Habit> = after.iter().filter(|h| h.id != habit.id).cloned().collect();
+    let locale = db
+        .get_locale()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let records = RecordTracker::new_records(&before, &after, habit, locale);
+    for record in records {
+        let _ = app.emit(PERSONAL_RECORD_EVENT, record);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_personal_records(
+    habit_name: Option<String>,
+    category: Option<String>,
+    state: State<AppState>,
+) -> Result<Vec<PersonalRecord>, String> {
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(RecordTracker::personal_records(&habits)
+        .into_iter()
+        .filter(|record| match record.subject.kind {
+            SubjectKind::Habit => {
+                category.is_none()
+                    && habit_name
+                        .as_ref()
+                        .is_none_or(|name| *name == record.subject.name)
+            }
+            SubjectKind::Category => {
+                habit_name.is_none() && category.as_ref().is_none_or(|c| *c == record.subject.name)
+            }
+        })
+        .collect())
+}
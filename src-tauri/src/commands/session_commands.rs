@@ -0,0 +1,116 @@
+use chrono::{Duration, Local, Utc};
+use tauri::{AppHandle, State};
+
+use crate::commands::{
+    announce_new_records, parse_date_or, report_evaluation_error, unlock_new_achievements, AppState,
+};
+use crate::models::{
+    normalize_tags, ActiveSession, Category, DurationReport, Habit, SessionStartInput, TimeGrouping,
+};
+use crate::statistics::StatisticsCalculator;
+
+#[tauri::command]
+pub fn start_session(
+    input: SessionStartInput,
+    state: State<AppState>,
+) -> Result<ActiveSession, String> {
+    let habit_name = input.name.trim().to_string();
+    if habit_name.is_empty() {
+        return Err("Habit name must not be empty".to_string());
+    }
+
+    let session = ActiveSession {
+        habit_name,
+        category: Category::from_str(&input.category).as_str().to_string(),
+        started_at: Utc::now(),
+        notes: input.notes,
+        tags: normalize_tags(&input.tags),
+    };
+
+    let started = state
+        .db
+        .start_session(&session)
+        .map_err(|e| format!("Database error: {}", e))?;
+    if !started {
+        return Err(format!(
+            "A timer is already running for {}",
+            session.habit_name
+        ));
+    }
+
+    Ok(session)
+}
+
+#[tauri::command]
+pub fn stop_session(
+    habit_name: String,
+    compliance_level: Option<u8>,
+    notes: Option<String>,
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<Habit, String> {
+    if let Some(level) = compliance_level {
+        let scale = state
+            .db
+            .get_habit_scale(&habit_name)
+            .map_err(|e| format!("Database error: {}", e))?;
+        if let Some(scale) = scale {
+            scale.validate(level)?;
+        }
+    }
+
+    let habit = state
+        .db
+        .finish_session(&habit_name, Utc::now(), compliance_level, notes)
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("No running timer for {}", habit_name))?;
+
+    if let Err(e) = unlock_new_achievements(&app, &state.db) {
+        report_evaluation_error(&app, e);
+    }
+    if let Err(e) = announce_new_records(&app, &state.db, &habit) {
+        report_evaluation_error(&app, e);
+    }
+
+    Ok(habit)
+}
+
+#[tauri::command]
+pub fn cancel_session(habit_name: String, state: State<AppState>) -> Result<bool, String> {
+    state
+        .db
+        .cancel_session(&habit_name)
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[tauri::command]
+pub fn get_active_sessions(state: State<AppState>) -> Result<Vec<ActiveSession>, String> {
+    state
+        .db
+        .get_active_sessions()
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[tauri::command]
+pub fn get_duration_report(
+    start: Option<String>,
+    end: Option<String>,
+    period: Option<TimeGrouping>,
+    state: State<AppState>,
+) -> Result<DurationReport, String> {
+    let today = Local::now().date_naive();
+    let start_date = parse_date_or(start, today - Duration::days(29))?;
+    let end_date = parse_date_or(end, today)?;
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(StatisticsCalculator::calculate_duration_report(
+        &habits,
+        start_date,
+        end_date,
+        period.unwrap_or(TimeGrouping::Day),
+    ))
+}
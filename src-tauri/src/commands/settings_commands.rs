@@ -0,0 +1,43 @@
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::i18n::Locale;
+
+// Parameter locale dari frontend diutamakan, kalau kosong pakai pengaturan tersimpan
+pub fn resolve_locale(locale: Option<String>, state: &AppState) -> Result<Locale, String> {
+    match locale {
+        Some(code) => {
+            Locale::from_code(&code).ok_or_else(|| format!("Unsupported locale: {}", code))
+        }
+        None => state
+            .db
+            .get_locale()
+            .map_err(|e| format!("Database error: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub fn get_locale(state: State<AppState>) -> Result<Locale, String> {
+    state
+        .db
+        .get_locale()
+        .map_err(|e| format!("Database error: {}", e))
+}
+
+#[tauri::command]
+pub fn set_locale(locale: String, state: State<AppState>) -> Result<Locale, String> {
+    let locale =
+        Locale::from_code(&locale).ok_or_else(|| format!("Unsupported locale: {}", locale))?;
+
+    state
+        .db
+        .set_locale(locale)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(locale)
+}
+
+#[tauri::command]
+pub fn get_available_locales() -> Vec<Locale> {
+    Locale::ALL.to_vec()
+}
@@ -0,0 +1,151 @@
+use chrono::{Duration, Local, Utc};
+use tauri::{AppHandle, State};
+use uuid::Uuid;
+
+use crate::commands::{
+    announce_new_records, parse_date_or, report_evaluation_error, unlock_new_achievements, AppState,
+};
+use crate::i18n::text;
+use crate::models::{Category, Habit, SleepInput, SleepReport};
+use crate::statistics::{SleepAnalyzer, DEFAULT_SLEEP_TARGET_HOURS, MAX_SLEEP_HOURS};
+
+const SLEEP_TARGET_KEY: &str = "sleep_target_hours";
+
+fn stored_sleep_target(state: &AppState) -> Result<f64, String> {
+    Ok(state
+        .db
+        .get_setting(SLEEP_TARGET_KEY)
+        .map_err(|e| format!("Database error: {}", e))?
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_SLEEP_TARGET_HOURS))
+}
+
+#[tauri::command]
+pub fn log_sleep(
+    input: SleepInput,
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<Habit, String> {
+    let bedtime = chrono::DateTime::parse_from_rfc3339(&input.bedtime)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| format!("Invalid bedtime: {}", e))?;
+    let mut wake_time = chrono::DateTime::parse_from_rfc3339(&input.wake_time)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| format!("Invalid wake time: {}", e))?;
+
+    // Jam bangun yang lebih awal dari jam tidur berarti bangun keesokan harinya
+    if wake_time <= bedtime {
+        wake_time += Duration::days(1);
+    }
+    // Selisih lebih dari sehari ke belakang tetap negatif setelah digeser
+    if wake_time <= bedtime {
+        return Err("Wake time must be after bedtime".to_string());
+    }
+    if (wake_time - bedtime).num_minutes() as f64 > MAX_SLEEP_HOURS * 60.0 {
+        return Err(format!(
+            "Sleep duration must not exceed {} hours",
+            MAX_SLEEP_HOURS
+        ));
+    }
+
+    let name = match input
+        .name
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+    {
+        Some(name) => name,
+        None => {
+            let locale = state
+                .db
+                .get_locale()
+                .map_err(|e| format!("Database error: {}", e))?;
+            text(locale, "sleep.default_name")
+        }
+    };
+
+    if let Some(level) = input.compliance_level {
+        state
+            .db
+            .get_habit_scale(&name)
+            .map_err(|e| format!("Database error: {}", e))?
+            .unwrap_or_default()
+            .validate(level)?;
+    }
+
+    let habit = Habit {
+        id: Uuid::new_v4().to_string(),
+        name,
+        category: Category::Sleep,
+        timestamp: bedtime,
+        compliance_level: input.compliance_level,
+        notes: input.notes,
+        tags: Vec::new(),
+        end_timestamp: Some(wake_time),
+        quantity: None,
+    };
+
+    state
+        .db
+        .insert_habit(&habit)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    if let Err(e) = unlock_new_achievements(&app, &state.db) {
+        report_evaluation_error(&app, e);
+    }
+    if let Err(e) = announce_new_records(&app, &state.db, &habit) {
+        report_evaluation_error(&app, e);
+    }
+
+    Ok(habit)
+}
+
+#[tauri::command]
+pub fn get_sleep_report(
+    start: Option<String>,
+    end: Option<String>,
+    target_hours: Option<f64>,
+    state: State<AppState>,
+) -> Result<SleepReport, String> {
+    let today = Local::now().date_naive();
+    let start_date = parse_date_or(start, today - Duration::days(29))?;
+    let end_date = parse_date_or(end, today)?;
+
+    let target_hours = match target_hours {
+        Some(hours) => hours,
+        None => stored_sleep_target(&state)?,
+    };
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(SleepAnalyzer::analyze(
+        &habits,
+        start_date,
+        end_date,
+        target_hours,
+    ))
+}
+
+#[tauri::command]
+pub fn get_sleep_target(state: State<AppState>) -> Result<f64, String> {
+    stored_sleep_target(&state)
+}
+
+#[tauri::command]
+pub fn set_sleep_target(hours: f64, state: State<AppState>) -> Result<f64, String> {
+    if !(hours > 0.0 && hours <= MAX_SLEEP_HOURS) {
+        return Err(format!(
+            "Sleep target must be between 0 and {} hours",
+            MAX_SLEEP_HOURS
+        ));
+    }
+
+    state
+        .db
+        .set_setting(SLEEP_TARGET_KEY, &hours.to_string())
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(hours)
+}
@@ -0,0 +1,265 @@
+use chrono::{Local, NaiveDate, Utc};
+use std::fs;
+use std::path::Path;
+use tauri::State;
+
+use crate::commands::{parse_date_or, resolve_locale, AppState};
+use crate::models::{
+    CsvColumnMapping, CsvExportOptions, CsvPreview, EntryImportReport, Goal, Habit,
+    HabiticaImportOptions, IcsExportOptions, IcsImportOptions, ImportRowError, LoopImportOptions,
+    MarkdownExportOptions, MarkdownExportResult,
+};
+use crate::statistics::StatisticsCalculator;
+use crate::transfer::{
+    run_import, validate_date_format, CsvTransfer, HabiticaImporter, IcsExporter, IcsImporter,
+    LoopImporter, MarkdownExporter, ParsedImport, PdfReport,
+};
+
+const DEFAULT_PREVIEW_ROWS: usize = 20;
+
+// Rentang tanggal lokal, kosong = seluruh riwayat
+pub fn habits_in_range(
+    state: &AppState,
+    start_date: Option<String>,
+    end_date: Option<String>,
+) -> Result<Vec<Habit>, String> {
+    let start = parse_date_or(start_date, NaiveDate::MIN)?;
+    let end = parse_date_or(end_date, NaiveDate::MAX)?;
+
+    let mut habits: Vec<Habit> = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?
+        .into_iter()
+        .filter(|h| {
+            let date = h.timestamp.with_timezone(&Local).date_naive();
+            date >= start && date <= end
+        })
+        .collect();
+    habits.sort_by_key(|h| h.timestamp);
+    Ok(habits)
+}
+
+fn parse_csv_file(
+    path: &str,
+    mapping: &CsvColumnMapping,
+    state: &AppState,
+) -> Result<CsvPreview, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("File error: {}", e))?;
+    let scales = state
+        .db
+        .get_habit_scales()
+        .map_err(|e| format!("Database error: {}", e))?;
+    CsvTransfer::parse(&contents, mapping, &scales)
+}
+
+#[tauri::command]
+pub fn export_csv(
+    path: String,
+    options: CsvExportOptions,
+    state: State<AppState>,
+) -> Result<usize, String> {
+    let habits = habits_in_range(&state, options.start_date.clone(), options.end_date.clone())?;
+    let contents = CsvTransfer::export(&habits, &options)?;
+    fs::write(&path, contents).map_err(|e| format!("File error: {}", e))?;
+    Ok(habits.len())
+}
+
+#[tauri::command]
+pub fn preview_csv_import(
+    path: String,
+    mapping: CsvColumnMapping,
+    limit: Option<usize>,
+    state: State<AppState>,
+) -> Result<CsvPreview, String> {
+    let mut preview = parse_csv_file(&path, &mapping, &state)?;
+    preview.rows.truncate(limit.unwrap_or(DEFAULT_PREVIEW_ROWS));
+    Ok(preview)
+}
+
+// Baris yang error dilewati dan dilaporkan, sisanya masuk dalam satu transaksi
+#[tauri::command]
+pub fn import_csv(
+    path: String,
+    mapping: CsvColumnMapping,
+    dry_run: Option<bool>,
+    state: State<AppState>,
+) -> Result<EntryImportReport, String> {
+    let preview = parse_csv_file(&path, &mapping, &state)?;
+
+    let mut parsed = ParsedImport {
+        total_rows: preview.total_rows,
+        ..Default::default()
+    };
+    for row in preview.rows {
+        match row.entry {
+            Some(entry) => parsed.entries.push(entry),
+            None => parsed
+                .errors
+                .extend(row.errors.into_iter().map(|message| ImportRowError {
+                    row: row.row,
+                    message,
+                })),
+        }
+    }
+
+    run_import(&state.db, "csv", parsed, dry_run.unwrap_or(true))
+}
+
+// File .db dari Loop Habit Tracker dibuka read-only; id entry diturunkan dari habit dan tanggal
+// Loop, jadi import ulang tidak membuat duplikat
+#[tauri::command]
+pub fn import_loop_backup(
+    path: String,
+    options: Option<LoopImportOptions>,
+    dry_run: Option<bool>,
+    state: State<AppState>,
+) -> Result<EntryImportReport, String> {
+    let parsed = LoopImporter::parse(Path::new(&path), &options.unwrap_or_default())?;
+    run_import(&state.db, "loop", parsed, dry_run.unwrap_or(true))
+}
+
+#[tauri::command]
+pub fn import_habitica(
+    path: String,
+    options: Option<HabiticaImportOptions>,
+    dry_run: Option<bool>,
+    state: State<AppState>,
+) -> Result<EntryImportReport, String> {
+    let contents = fs::read_to_string(&path).map_err(|e| format!("File error: {}", e))?;
+    let parsed = HabiticaImporter::parse(&contents, &options.unwrap_or_default())?;
+    run_import(&state.db, "habitica", parsed, dry_run.unwrap_or(true))
+}
+
+#[tauri::command]
+pub fn export_ics(
+    path: String,
+    options: IcsExportOptions,
+    state: State<AppState>,
+) -> Result<usize, String> {
+    let habits: Vec<Habit> =
+        habits_in_range(&state, options.start_date.clone(), options.end_date.clone())?
+            .into_iter()
+            .filter(|h| options.filter.matches(h))
+            .collect();
+
+    let goals: Vec<Goal> = if options.include_goals {
+        state
+            .db
+            .get_goals()
+            .map_err(|e| format!("Database error: {}", e))?
+            .into_iter()
+            .filter(|g| IcsExporter::goal_matches(g, &options.filter))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let contents = IcsExporter::export(&habits, &goals, Utc::now());
+    fs::write(&path, contents).map_err(|e| format!("File error: {}", e))?;
+    Ok(habits.len())
+}
+
+#[tauri::command]
+pub fn import_ics(
+    path: String,
+    options: IcsImportOptions,
+    dry_run: Option<bool>,
+    state: State<AppState>,
+) -> Result<EntryImportReport, String> {
+    let start = parse_date_or(options.start_date.clone(), NaiveDate::MIN)?;
+    let end = parse_date_or(options.end_date.clone(), Local::now().date_naive())?;
+    if options.rules.is_empty() {
+        return Err("At least one matching rule is required".to_string());
+    }
+
+    let contents = fs::read_to_string(&path).map_err(|e| format!("File error: {}", e))?;
+    let parsed = IcsImporter::parse(&contents, &options, start, end, Utc::now())?;
+    run_import(&state.db, "ics", parsed, dry_run.unwrap_or(true))
+}
+
+// Satu file per hari yang punya entry, ditulis ke folder vault (misalnya folder daily notes)
+#[tauri::command]
+pub fn export_markdown_journal(
+    folder: String,
+    options: MarkdownExportOptions,
+    state: State<AppState>,
+) -> Result<MarkdownExportResult, String> {
+    let start = parse_date_or(options.start_date.clone(), NaiveDate::MIN)?;
+    let end = parse_date_or(options.end_date.clone(), NaiveDate::MAX)?;
+    let file_name_format = options.file_name_format.as_deref().unwrap_or("%Y-%m-%d");
+    validate_date_format(file_name_format)?;
+    // Dicek sebelum menulis apa pun, supaya format yang salah tidak menghasilkan ekspor setengah jadi
+    MarkdownExporter::file_name(Local::now().date_naive(), file_name_format)?;
+
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let scales = state
+        .db
+        .get_habit_scales()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let folder_path = Path::new(&folder);
+    fs::create_dir_all(folder_path).map_err(|e| format!("File error: {}", e))?;
+
+    let mut result = MarkdownExportResult {
+        folder: folder.clone(),
+        ..Default::default()
+    };
+    for note in MarkdownExporter::day_notes(&habits, start, end, &scales) {
+        let path = folder_path.join(MarkdownExporter::file_name(note.date, file_name_format)?);
+        let existing = match fs::read_to_string(&path) {
+            Ok(contents) => Some(contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(format!("File error: {}", e)),
+        };
+
+        let contents = MarkdownExporter::render(&note, &scales, existing.as_deref());
+        match existing {
+            Some(previous) if previous == contents => {
+                result.unchanged += 1;
+                continue;
+            }
+            Some(_) => result.updated += 1,
+            None => result.created += 1,
+        }
+        fs::write(&path, contents).map_err(|e| format!("File error: {}", e))?;
+    }
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn export_monthly_report_pdf(
+    path: String,
+    year: i32,
+    month: u32,
+    locale: Option<String>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    if NaiveDate::from_ymd_opt(year, month, 1).is_none() {
+        return Err(format!("Invalid month: {}-{}", year, month));
+    }
+    let locale = resolve_locale(locale, &state)?;
+    let habits = state
+        .db
+        .get_all_habits()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let preferences = state
+        .db
+        .get_insight_preferences()
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let today = Local::now().date_naive();
+    let stats = StatisticsCalculator::calculate_monthly_stats(
+        &habits,
+        year,
+        month,
+        &preferences,
+        today,
+        locale,
+    );
+    let pdf = PdfReport::render(&stats, month, locale, today)?;
+    fs::write(&path, pdf).map_err(|e| format!("File error: {}", e))
+}
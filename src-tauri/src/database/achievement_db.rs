@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, Result as SqliteResult};
+use std::collections::HashMap;
+
+use crate::database::HabitDatabase;
+
+pub(super) fn create_tables(conn: &Connection) -> SqliteResult<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS achievements (
+            id TEXT PRIMARY KEY,
+            unlocked_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+impl HabitDatabase {
+    pub fn get_unlocked_achievements(&self) -> SqliteResult<HashMap<String, DateTime<Utc>>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT id, unlocked_at FROM achievements")?;
+
+        let unlocked = stmt
+            .query_map([], |row| {
+                let id: String = row.get(0)?;
+                let unlocked_at: String = row.get(1)?;
+                Ok((id, unlocked_at))
+            })?
+            .filter_map(|r| r.ok())
+            .filter_map(|(id, unlocked_at)| {
+                DateTime::parse_from_rfc3339(&unlocked_at)
+                    .ok()
+                    .map(|dt| (id, dt.with_timezone(&Utc)))
+            })
+            .collect();
+
+        Ok(unlocked)
+    }
+
+    // Mengembalikan false kalau achievement sudah pernah terbuka
+    pub fn unlock_achievement(&self, id: &str, unlocked_at: DateTime<Utc>) -> SqliteResult<bool> {
+        let conn = self.conn.lock().unwrap();
+        let affected = conn.execute(
+            "INSERT OR IGNORE INTO achievements (id, unlocked_at) VALUES (?1, ?2)",
+            params![id, unlocked_at.to_rfc3339()],
+        )?;
+        Ok(affected > 0)
+    }
+}
@@ -0,0 +1,334 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, Result as SqliteResult};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::database::goal_db::{goal_from_row, upsert_goal_row};
+use crate::database::habit_db::{habit_from_row, insert_habit_row};
+use crate::database::HabitDatabase;
+use crate::models::{
+    BackupData, BackupDocument, ImportCounts, ImportMode, ImportSummary, InsightRuleSettings,
+};
+
+fn query_pairs<T>(
+    conn: &Connection,
+    sql: &str,
+    parse: impl Fn(String) -> Option<T>,
+) -> SqliteResult<BTreeMap<String, T>> {
+    let mut stmt = conn.prepare(sql)?;
+    let pairs = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .filter_map(|r| r.ok())
+        .filter_map(|(key, value)| parse(value).map(|v| (key, v)))
+        .collect();
+    Ok(pairs)
+}
+
+fn parse_rfc3339(value: String) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn read_backup_data(conn: &Connection) -> SqliteResult<BackupData> {
+    let mut stmt = conn.prepare(
+        "SELECT id, name, category, timestamp, compliance_level, notes, tags, end_timestamp,
+                quantity
+         FROM habits ORDER BY timestamp ASC",
+    )?;
+    let habits = stmt
+        .query_map([], habit_from_row)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut stmt = conn.prepare(
+        "SELECT id, title, scope_type, scope_value, metric, target, period,
+                start_date, end_date, created_at, archived
+         FROM goals ORDER BY created_at ASC",
+    )?;
+    let goals = stmt
+        .query_map([], goal_from_row)?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut stmt =
+        conn.prepare("SELECT rule_id, enabled, priority, cooldown_days FROM insight_settings")?;
+    let insight_settings = stmt
+        .query_map([], |row| {
+            Ok(InsightRuleSettings {
+                rule_id: row.get(0)?,
+                enabled: row.get(1)?,
+                priority: row.get(2)?,
+                cooldown_days: row.get(3)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut stmt = conn.prepare("SELECT category, weight FROM balance_targets")?;
+    let balance_targets = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(BackupData {
+        habits,
+        goals,
+        habit_scales: query_pairs(conn, "SELECT habit_name, scale FROM habit_scales", |json| {
+            serde_json::from_str(&json).ok()
+        })?,
+        achievements: query_pairs(
+            conn,
+            "SELECT id, unlocked_at FROM achievements",
+            parse_rfc3339,
+        )?,
+        insight_settings,
+        insight_dismissals: query_pairs(
+            conn,
+            "SELECT insight_id, dismissed_at FROM insight_dismissals",
+            parse_rfc3339,
+        )?,
+        settings: query_pairs(conn, "SELECT key, value FROM app_settings", Some)?,
+        balance_targets,
+    })
+}
+
+// Satu bagian backup: hitung perubahan terhadap data lokal lalu tulis yang perlu ditulis.
+// Mode replace mengosongkan tabel dulu, mode merge hanya menambah id yang belum ada.
+fn import_section<'a, T: Serialize + 'a>(
+    conn: &Connection,
+    table: &str,
+    mode: ImportMode,
+    current: BTreeMap<String, &T>,
+    incoming: impl IntoIterator<Item = (String, &'a T)>,
+    write: impl Fn(&Connection, &str, &T) -> SqliteResult<()>,
+) -> SqliteResult<ImportCounts> {
+    let mut counts = ImportCounts::default();
+    let mut deduplicated: BTreeMap<String, &T> = BTreeMap::new();
+    for (key, item) in incoming {
+        if deduplicated.insert(key, item).is_some() {
+            counts.duplicates += 1;
+        }
+    }
+
+    let same = |a: &T, b: &T| serde_json::to_value(a).ok() == serde_json::to_value(b).ok();
+    let mut to_write = Vec::new();
+    for (key, item) in &deduplicated {
+        match (current.get(key), mode) {
+            (None, _) => {
+                counts.added += 1;
+                to_write.push((key, *item));
+            }
+            (Some(existing), _) if same(existing, item) => {
+                counts.unchanged += 1;
+                if mode == ImportMode::Replace {
+                    to_write.push((key, *item));
+                }
+            }
+            (Some(_), ImportMode::Merge) => counts.skipped += 1,
+            (Some(_), ImportMode::Replace) => {
+                counts.updated += 1;
+                to_write.push((key, *item));
+            }
+        }
+    }
+
+    if mode == ImportMode::Replace {
+        counts.removed = current
+            .keys()
+            .filter(|key| !deduplicated.contains_key(*key))
+            .count();
+        conn.execute(&format!("DELETE FROM {}", table), [])?;
+    }
+    for (key, item) in to_write {
+        write(conn, key, item)?;
+    }
+    Ok(counts)
+}
+
+impl HabitDatabase {
+    pub fn export_backup_data(&self) -> SqliteResult<BackupData> {
+        let conn = self.conn.lock().unwrap();
+        read_backup_data(&conn)
+    }
+
+    // Dry run menjalankan import yang sama di dalam transaksi lalu di-rollback,
+    // jadi ringkasannya selalu sama dengan hasil import sungguhan
+    pub fn import_backup(
+        &self,
+        document: &BackupDocument,
+        mode: ImportMode,
+        dry_run: bool,
+    ) -> SqliteResult<ImportSummary> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let current = read_backup_data(&tx)?;
+        let incoming = &document.data;
+
+        let habits = import_section(
+            &tx,
+            "habits",
+            mode,
+            current.habits.iter().map(|h| (h.id.clone(), h)).collect(),
+            incoming.habits.iter().map(|h| (h.id.clone(), h)),
+            |conn, _, habit| insert_habit_row(conn, habit),
+        )?;
+        let goals = import_section(
+            &tx,
+            "goals",
+            mode,
+            current.goals.iter().map(|g| (g.id.clone(), g)).collect(),
+            incoming.goals.iter().map(|g| (g.id.clone(), g)),
+            |conn, _, goal| upsert_goal_row(conn, goal),
+        )?;
+        let habit_scales = import_section(
+            &tx,
+            "habit_scales",
+            mode,
+            current
+                .habit_scales
+                .iter()
+                .map(|(k, v)| (k.clone(), v))
+                .collect(),
+            incoming.habit_scales.iter().map(|(k, v)| (k.clone(), v)),
+            |conn, name, scale| {
+                let scale_json = serde_json::to_string(scale)
+                    .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+                conn.execute(
+                    "INSERT OR REPLACE INTO habit_scales (habit_name, scale, updated_at)
+                     VALUES (?1, ?2, ?3)",
+                    params![name, scale_json, Utc::now().to_rfc3339()],
+                )?;
+                Ok(())
+            },
+        )?;
+        let achievements = import_section(
+            &tx,
+            "achievements",
+            mode,
+            current
+                .achievements
+                .iter()
+                .map(|(k, v)| (k.clone(), v))
+                .collect(),
+            incoming.achievements.iter().map(|(k, v)| (k.clone(), v)),
+            |conn, id, unlocked_at| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO achievements (id, unlocked_at) VALUES (?1, ?2)",
+                    params![id, unlocked_at.to_rfc3339()],
+                )?;
+                Ok(())
+            },
+        )?;
+        let insight_settings = import_section(
+            &tx,
+            "insight_settings",
+            mode,
+            current
+                .insight_settings
+                .iter()
+                .map(|s| (s.rule_id.clone(), s))
+                .collect(),
+            incoming
+                .insight_settings
+                .iter()
+                .map(|s| (s.rule_id.clone(), s)),
+            |conn, _, settings| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO insight_settings
+                        (rule_id, enabled, priority, cooldown_days)
+                     VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        settings.rule_id,
+                        settings.enabled,
+                        settings.priority,
+                        settings.cooldown_days,
+                    ],
+                )?;
+                Ok(())
+            },
+        )?;
+        let insight_dismissals = import_section(
+            &tx,
+            "insight_dismissals",
+            mode,
+            current
+                .insight_dismissals
+                .iter()
+                .map(|(k, v)| (k.clone(), v))
+                .collect(),
+            incoming
+                .insight_dismissals
+                .iter()
+                .map(|(k, v)| (k.clone(), v)),
+            |conn, id, dismissed_at| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO insight_dismissals (insight_id, dismissed_at)
+                     VALUES (?1, ?2)",
+                    params![id, dismissed_at.to_rfc3339()],
+                )?;
+                Ok(())
+            },
+        )?;
+        let settings = import_section(
+            &tx,
+            "app_settings",
+            mode,
+            current
+                .settings
+                .iter()
+                .map(|(k, v)| (k.clone(), v))
+                .collect(),
+            incoming.settings.iter().map(|(k, v)| (k.clone(), v)),
+            |conn, key, value| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO app_settings (key, value) VALUES (?1, ?2)",
+                    params![key, value],
+                )?;
+                Ok(())
+            },
+        )?;
+        let balance_targets = import_section(
+            &tx,
+            "balance_targets",
+            mode,
+            current
+                .balance_targets
+                .iter()
+                .map(|(k, v)| (k.clone(), v))
+                .collect(),
+            incoming.balance_targets.iter().map(|(k, v)| (k.clone(), v)),
+            |conn, category, weight| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO balance_targets (category, weight) VALUES (?1, ?2)",
+                    params![category, weight],
+                )?;
+                Ok(())
+            },
+        )?;
+
+        if dry_run {
+            tx.rollback()?;
+        } else {
+            tx.commit()?;
+        }
+
+        Ok(ImportSummary {
+            mode,
+            dry_run,
+            format_version: document.format_version,
+            app_version: document.app_version.clone(),
+            exported_at: document.exported_at,
+            habits,
+            goals,
+            habit_scales,
+            achievements,
+            insight_settings,
+            insight_dismissals,
+            settings,
+            balance_targets,
+        })
+    }
+}
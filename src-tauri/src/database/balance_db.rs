@@ -0,0 +1,45 @@
+use rusqlite::{params, Connection, Result as SqliteResult};
+use std::collections::HashMap;
+
+use crate::database::HabitDatabase;
+
+pub(super) fn create_tables(conn: &Connection) -> SqliteResult<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS balance_targets (
+            category TEXT PRIMARY KEY,
+            weight REAL NOT NULL
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+impl HabitDatabase {
+    pub fn get_balance_targets(&self) -> SqliteResult<HashMap<String, f64>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT category, weight FROM balance_targets")?;
+
+        let targets = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(targets)
+    }
+
+    pub fn set_balance_target(&self, category: &str, weight: f64) -> SqliteResult<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO balance_targets (category, weight) VALUES (?1, ?2)
+             ON CONFLICT(category) DO UPDATE SET weight = ?2",
+            params![category, weight],
+        )?;
+        Ok(())
+    }
+
+    pub fn reset_balance_targets(&self) -> SqliteResult<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM balance_targets", [])?;
+        Ok(())
+    }
+}
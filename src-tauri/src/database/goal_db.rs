@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Result as SqliteResult, Row};
+
+use crate::database::HabitDatabase;
+use crate::models::{Goal, GoalMetric, GoalPeriod, GoalScope};
+
+pub(super) fn create_tables(conn: &Connection) -> SqliteResult<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS goals (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            scope_type TEXT NOT NULL,
+            scope_value TEXT NOT NULL,
+            metric TEXT NOT NULL,
+            target REAL NOT NULL,
+            period TEXT NOT NULL,
+            start_date TEXT,
+            end_date TEXT,
+            created_at TEXT NOT NULL,
+            archived INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+pub(super) fn goal_from_row(row: &Row) -> SqliteResult<Goal> {
+    let scope_type: String = row.get(2)?;
+    let metric: String = row.get(4)?;
+    let period: String = row.get(6)?;
+    let created_at: String = row.get(9)?;
+
+    Ok(Goal {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        scope: GoalScope::from_parts(&scope_type, row.get(3)?),
+        metric: GoalMetric::from_str(&metric),
+        target: row.get(5)?,
+        period: GoalPeriod::from_str(&period),
+        start_date: row.get(7)?,
+        end_date: row.get(8)?,
+        created_at: DateTime::parse_from_rfc3339(&created_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+        archived: row.get(10)?,
+    })
+}
+
+// Dipakai juga oleh import backup di dalam transaksi
+pub(super) fn upsert_goal_row(conn: &Connection, goal: &Goal) -> SqliteResult<()> {
+    let (scope_type, scope_value) = goal.scope.parts();
+    conn.execute(
+        "INSERT INTO goals (id, title, scope_type, scope_value, metric, target, period,
+                            start_date, end_date, created_at, archived)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+         ON CONFLICT(id) DO UPDATE SET
+            title = ?2, scope_type = ?3, scope_value = ?4, metric = ?5, target = ?6,
+            period = ?7, start_date = ?8, end_date = ?9, archived = ?11",
+        params![
+            goal.id,
+            goal.title,
+            scope_type,
+            scope_value,
+            goal.metric.as_str(),
+            goal.target,
+            goal.period.as_str(),
+            goal.start_date,
+            goal.end_date,
+            goal.created_at.to_rfc3339(),
+            goal.archived,
+        ],
+    )?;
+    Ok(())
+}
+
+impl HabitDatabase {
+    pub fn upsert_goal(&self, goal: &Goal) -> SqliteResult<()> {
+        let conn = self.conn.lock().unwrap();
+        upsert_goal_row(&conn, goal)
+    }
+
+    pub fn get_goals(&self) -> SqliteResult<Vec<Goal>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, title, scope_type, scope_value, metric, target, period,
+                    start_date, end_date, created_at, archived
+             FROM goals ORDER BY created_at ASC",
+        )?;
+
+        let goals = stmt
+            .query_map([], goal_from_row)?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(goals)
+    }
+
+    pub fn get_goal(&self, id: &str) -> SqliteResult<Option<Goal>> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT id, title, scope_type, scope_value, metric, target, period,
+                    start_date, end_date, created_at, archived
+             FROM goals WHERE id = ?1",
+            params![id],
+            goal_from_row,
+        )
+        .optional()
+    }
+
+    pub fn delete_goal(&self, id: &str) -> SqliteResult<bool> {
+        let conn = self.conn.lock().unwrap();
+        let affected = conn.execute("DELETE FROM goals WHERE id = ?1", params![id])?;
+        Ok(affected > 0)
+    }
+}
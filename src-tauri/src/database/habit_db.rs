@@ -1,12 +1,94 @@
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection, Result as SqliteResult};
+use rusqlite::{params, Connection, OptionalExtension, Result as SqliteResult, Row};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
-use crate::models::{Category, Habit};
+use crate::database::{
+    achievement_db, balance_db, goal_db, insight_db, session_db, settings_db,
+};
+use crate::models::{Category, Habit, RatingScale};
 
 pub struct HabitDatabase {
-    conn: Mutex<Connection>,
+    pub(super) conn: Mutex<Connection>,
+}
+
+// Kolom yang ditambahkan setelah rilis pertama, supaya habits.db lama tetap bisa dibuka
+pub(super) fn ensure_column(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> SqliteResult<()> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|r| r.ok())
+        .any(|name| name == column);
+
+    if !exists {
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+pub(super) fn tags_to_sql(tags: &[String]) -> Option<String> {
+    if tags.is_empty() {
+        None
+    } else {
+        serde_json::to_string(tags).ok()
+    }
+}
+
+pub(super) fn habit_from_row(row: &Row) -> SqliteResult<Habit> {
+    let timestamp_str: String = row.get(3)?;
+    let timestamp = DateTime::parse_from_rfc3339(&timestamp_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+
+    let category_str: String = row.get(2)?;
+    let tags_json: Option<String> = row.get(6)?;
+    let end_timestamp: Option<String> = row.get(7)?;
+
+    Ok(Habit {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        category: Category::from_str(&category_str),
+        timestamp,
+        compliance_level: row.get(4)?,
+        notes: row.get(5)?,
+        tags: tags_json
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default(),
+        end_timestamp: end_timestamp
+            .and_then(|ts| DateTime::parse_from_rfc3339(&ts).ok())
+            .map(|dt| dt.with_timezone(&Utc)),
+        quantity: row.get(8)?,
+    })
+}
+
+// Dipakai juga di dalam transaksi (session, import), jadi menerima Connection langsung
+pub(super) fn insert_habit_row(conn: &Connection, habit: &Habit) -> SqliteResult<()> {
+    conn.execute(
+        "INSERT INTO habits (id, name, category, timestamp, compliance_level, notes, tags, end_timestamp, quantity, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            habit.id,
+            habit.name,
+            habit.category.as_str(),
+            habit.timestamp.to_rfc3339(),
+            habit.compliance_level,
+            habit.notes,
+            tags_to_sql(&habit.tags),
+            habit.end_timestamp.map(|ts| ts.to_rfc3339()),
+            habit.quantity,
+            Utc::now().to_rfc3339(),
+        ],
+    )?;
+    Ok(())
 }
 
 impl HabitDatabase {
@@ -28,6 +110,10 @@ impl HabitDatabase {
             [],
         )?;
 
+        ensure_column(&conn, "habits", "tags", "TEXT")?;
+        ensure_column(&conn, "habits", "end_timestamp", "TEXT")?;
+        ensure_column(&conn, "habits", "quantity", "REAL")?;
+
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_habits_timestamp ON habits(timestamp)",
             [],
@@ -38,6 +124,22 @@ impl HabitDatabase {
             [],
         )?;
 
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS habit_scales (
+                habit_name TEXT PRIMARY KEY,
+                scale TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        goal_db::create_tables(&conn)?;
+        achievement_db::create_tables(&conn)?;
+        insight_db::create_tables(&conn)?;
+        settings_db::create_tables(&conn)?;
+        session_db::create_tables(&conn)?;
+        balance_db::create_tables(&conn)?;
+
         Ok(Self {
             conn: Mutex::new(conn),
         })
@@ -45,47 +147,19 @@ impl HabitDatabase {
 
     pub fn insert_habit(&self, habit: &Habit) -> SqliteResult<()> {
         let conn = self.conn.lock().unwrap();
-        conn.execute(
-            "INSERT INTO habits (id, name, category, timestamp, compliance_level, notes, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-            params![
-                habit.id,
-                habit.name,
-                habit.category.as_str(),
-                habit.timestamp.to_rfc3339(),
-                habit.compliance_level,
-                habit.notes,
-                Utc::now().to_rfc3339(),
-            ],
-        )?;
-        Ok(())
+        insert_habit_row(&conn, habit)
     }
 
     pub fn get_all_habits(&self) -> SqliteResult<Vec<Habit>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, name, category, timestamp, compliance_level, notes 
+            "SELECT id, name, category, timestamp, compliance_level, notes, tags, end_timestamp,
+                    quantity
              FROM habits ORDER BY timestamp DESC",
         )?;
 
         let habits = stmt
-            .query_map([], |row| {
-                let timestamp_str: String = row.get(3)?;
-                let timestamp = DateTime::parse_from_rfc3339(&timestamp_str)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now());
-
-                let category_str: String = row.get(2)?;
-
-                Ok(Habit {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    category: Category::from_str(&category_str),
-                    timestamp,
-                    compliance_level: row.get(4)?,
-                    notes: row.get(5)?,
-                })
-            })?
+            .query_map([], habit_from_row)?
             .filter_map(|r| r.ok())
             .collect();
 
@@ -99,30 +173,15 @@ impl HabitDatabase {
     ) -> SqliteResult<Vec<Habit>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, name, category, timestamp, compliance_level, notes 
+            "SELECT id, name, category, timestamp, compliance_level, notes, tags, end_timestamp,
+                    quantity
              FROM habits 
              WHERE timestamp >= ?1 AND timestamp <= ?2
              ORDER BY timestamp DESC",
         )?;
 
         let habits = stmt
-            .query_map(params![start.to_rfc3339(), end.to_rfc3339()], |row| {
-                let timestamp_str: String = row.get(3)?;
-                let timestamp = DateTime::parse_from_rfc3339(&timestamp_str)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now());
-
-                let category_str: String = row.get(2)?;
-
-                Ok(Habit {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    category: Category::from_str(&category_str),
-                    timestamp,
-                    compliance_level: row.get(4)?,
-                    notes: row.get(5)?,
-                })
-            })?
+            .query_map(params![start.to_rfc3339(), end.to_rfc3339()], habit_from_row)?
             .filter_map(|r| r.ok())
             .collect();
 
@@ -132,30 +191,15 @@ impl HabitDatabase {
     pub fn get_habits_by_category(&self, category: &str) -> SqliteResult<Vec<Habit>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, name, category, timestamp, compliance_level, notes 
+            "SELECT id, name, category, timestamp, compliance_level, notes, tags, end_timestamp,
+                    quantity
              FROM habits 
              WHERE category = ?1
              ORDER BY timestamp DESC",
         )?;
 
         let habits = stmt
-            .query_map(params![category], |row| {
-                let timestamp_str: String = row.get(3)?;
-                let timestamp = DateTime::parse_from_rfc3339(&timestamp_str)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now());
-
-                let category_str: String = row.get(2)?;
-
-                Ok(Habit {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    category: Category::from_str(&category_str),
-                    timestamp,
-                    compliance_level: row.get(4)?,
-                    notes: row.get(5)?,
-                })
-            })?
+            .query_map(params![category], habit_from_row)?
             .filter_map(|r| r.ok())
             .collect();
 
@@ -173,4 +217,56 @@ impl HabitDatabase {
         let count: i64 = conn.query_row("SELECT COUNT(*) FROM habits", [], |row| row.get(0))?;
         Ok(count as usize)
     }
-}
\ No newline at end of file
+
+    pub fn set_habit_scale(&self, habit_name: &str, scale: &RatingScale) -> SqliteResult<()> {
+        let scale_json = serde_json::to_string(scale)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO habit_scales (habit_name, scale, updated_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(habit_name) DO UPDATE SET scale = ?2, updated_at = ?3",
+            params![habit_name, scale_json, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_habit_scales(&self) -> SqliteResult<HashMap<String, RatingScale>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT habit_name, scale FROM habit_scales")?;
+
+        let scales = stmt
+            .query_map([], |row| {
+                let habit_name: String = row.get(0)?;
+                let scale_json: String = row.get(1)?;
+                Ok((habit_name, scale_json))
+            })?
+            .filter_map(|r| r.ok())
+            .filter_map(|(name, json)| serde_json::from_str(&json).ok().map(|scale| (name, scale)))
+            .collect();
+
+        Ok(scales)
+    }
+
+    pub fn get_habit_scale(&self, habit_name: &str) -> SqliteResult<Option<RatingScale>> {
+        let conn = self.conn.lock().unwrap();
+        let scale_json: Option<String> = conn
+            .query_row(
+                "SELECT scale FROM habit_scales WHERE habit_name = ?1",
+                params![habit_name],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(scale_json.and_then(|json| serde_json::from_str(&json).ok()))
+    }
+
+    pub fn delete_habit_scale(&self, habit_name: &str) -> SqliteResult<bool> {
+        let conn = self.conn.lock().unwrap();
+        let affected = conn.execute(
+            "DELETE FROM habit_scales WHERE habit_name = ?1",
+            params![habit_name],
+        )?;
+        Ok(affected > 0)
+    }
+}
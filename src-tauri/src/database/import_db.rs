@@ -0,0 +1,59 @@
+use rusqlite::Result as SqliteResult;
+use std::collections::HashSet;
+
+use crate::database::habit_db::insert_habit_row;
+use crate::database::HabitDatabase;
+use crate::models::{Habit, ImportCounts};
+
+impl HabitDatabase {
+    // Dipakai semua importer entry: satu transaksi, entry yang id-nya atau pasangan
+    // nama + timestamp-nya sudah ada dilewati, dan dry run di-rollback
+    pub fn import_entries(&self, entries: &[Habit], dry_run: bool) -> SqliteResult<ImportCounts> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+
+        let mut ids: HashSet<String> = HashSet::new();
+        let mut keys: HashSet<(String, String)> = HashSet::new();
+        {
+            let mut stmt = tx.prepare("SELECT id, name, timestamp FROM habits")?;
+            let rows = stmt.query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?;
+            for (id, name, timestamp) in rows.filter_map(|r| r.ok()) {
+                ids.insert(id);
+                keys.insert((name, timestamp));
+            }
+        }
+
+        let mut counts = ImportCounts::default();
+        let mut seen_ids: HashSet<&str> = HashSet::new();
+        let mut seen_keys: HashSet<(&str, String)> = HashSet::new();
+
+        for entry in entries {
+            let timestamp = entry.timestamp.to_rfc3339();
+            if !seen_ids.insert(entry.id.as_str())
+                || !seen_keys.insert((entry.name.as_str(), timestamp.clone()))
+            {
+                counts.duplicates += 1;
+                continue;
+            }
+            if ids.contains(&entry.id) || keys.contains(&(entry.name.clone(), timestamp)) {
+                counts.skipped += 1;
+                continue;
+            }
+            insert_habit_row(&tx, entry)?;
+            counts.added += 1;
+        }
+
+        if dry_run {
+            tx.rollback()?;
+        } else {
+            tx.commit()?;
+        }
+        Ok(counts)
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, Result as SqliteResult};
+
+use crate::database::HabitDatabase;
+use crate::models::{Insight, InsightPreferences, InsightRuleSettings, ShownInsight};
+
+pub(super) fn create_tables(conn: &Connection) -> SqliteResult<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS insight_settings (
+            rule_id TEXT PRIMARY KEY,
+            enabled INTEGER NOT NULL,
+            priority INTEGER NOT NULL,
+            cooldown_days INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS insight_dismissals (
+            insight_id TEXT PRIMARY KEY,
+            dismissed_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS insight_log (
+            insight_id TEXT PRIMARY KEY,
+            rule_id TEXT NOT NULL,
+            first_shown_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+impl HabitDatabase {
+    pub fn get_insight_preferences(&self) -> SqliteResult<InsightPreferences> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt =
+            conn.prepare("SELECT rule_id, enabled, priority, cooldown_days FROM insight_settings")?;
+        let settings = stmt
+            .query_map([], |row| {
+                Ok(InsightRuleSettings {
+                    rule_id: row.get(0)?,
+                    enabled: row.get(1)?,
+                    priority: row.get(2)?,
+                    cooldown_days: row.get(3)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .map(|s| (s.rule_id.clone(), s))
+            .collect();
+
+        let mut stmt = conn.prepare("SELECT insight_id FROM insight_dismissals")?;
+        let dismissed = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        let mut stmt =
+            conn.prepare("SELECT insight_id, rule_id, first_shown_at FROM insight_log")?;
+        let shown = stmt
+            .query_map([], |row| {
+                let first_shown_at: String = row.get(2)?;
+                Ok(ShownInsight {
+                    insight_id: row.get(0)?,
+                    rule_id: row.get(1)?,
+                    first_shown_at: DateTime::parse_from_rfc3339(&first_shown_at)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        let mut stmt = conn.prepare("SELECT category, weight FROM balance_targets")?;
+        let balance_weights = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(InsightPreferences {
+            settings,
+            dismissed,
+            shown,
+            balance_weights,
+        })
+    }
+
+    pub fn set_insight_rule_settings(&self, settings: &InsightRuleSettings) -> SqliteResult<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO insight_settings (rule_id, enabled, priority, cooldown_days)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(rule_id) DO UPDATE SET enabled = ?2, priority = ?3, cooldown_days = ?4",
+            params![
+                settings.rule_id,
+                settings.enabled,
+                settings.priority,
+                settings.cooldown_days,
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn dismiss_insight(&self, insight_id: &str) -> SqliteResult<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR IGNORE INTO insight_dismissals (insight_id, dismissed_at) VALUES (?1, ?2)",
+            params![insight_id, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    pub fn restore_insight(&self, insight_id: &str) -> SqliteResult<bool> {
+        let conn = self.conn.lock().unwrap();
+        let affected = conn.execute(
+            "DELETE FROM insight_dismissals WHERE insight_id = ?1",
+            params![insight_id],
+        )?;
+        Ok(affected > 0)
+    }
+
+    // Hanya waktu pertama tampil yang dicatat, untuk perhitungan cooldown
+    pub fn record_shown_insights(&self, insights: &[Insight]) -> SqliteResult<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let now = Utc::now().to_rfc3339();
+        for insight in insights {
+            tx.execute(
+                "INSERT OR IGNORE INTO insight_log (insight_id, rule_id, first_shown_at)
+                 VALUES (?1, ?2, ?3)",
+                params![insight.id, insight.rule_id, now],
+            )?;
+        }
+        tx.commit()
+    }
+}
@@ -1,3 +1,11 @@
+pub mod achievement_db;
+pub mod backup_db;
+pub mod balance_db;
+pub mod goal_db;
 pub mod habit_db;
+pub mod import_db;
+pub mod insight_db;
+pub mod session_db;
+pub mod settings_db;
 
-pub use habit_db::*;
\ No newline at end of file
+pub use habit_db::*;
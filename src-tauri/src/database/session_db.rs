@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Result as SqliteResult, Row};
+use uuid::Uuid;
+
+use crate::database::habit_db::{insert_habit_row, tags_to_sql};
+use crate::database::HabitDatabase;
+use crate::models::{ActiveSession, Category, Habit};
+
+pub(super) fn create_tables(conn: &Connection) -> SqliteResult<()> {
+    // habit_name sebagai primary key: satu habit hanya boleh punya satu timer berjalan
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS active_sessions (
+            habit_name TEXT PRIMARY KEY,
+            category TEXT NOT NULL,
+            started_at TEXT NOT NULL,
+            notes TEXT,
+            tags TEXT
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn session_from_row(row: &Row) -> SqliteResult<ActiveSession> {
+    let started_at: String = row.get(2)?;
+    let tags_json: Option<String> = row.get(4)?;
+
+    Ok(ActiveSession {
+        habit_name: row.get(0)?,
+        category: row.get(1)?,
+        started_at: DateTime::parse_from_rfc3339(&started_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+        notes: row.get(3)?,
+        tags: tags_json
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default(),
+    })
+}
+
+impl HabitDatabase {
+    // Mengembalikan false kalau habit ini sudah punya timer berjalan
+    pub fn start_session(&self, session: &ActiveSession) -> SqliteResult<bool> {
+        let conn = self.conn.lock().unwrap();
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO active_sessions (habit_name, category, started_at, notes, tags)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                session.habit_name,
+                session.category,
+                session.started_at.to_rfc3339(),
+                session.notes,
+                tags_to_sql(&session.tags),
+            ],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    pub fn get_active_sessions(&self) -> SqliteResult<Vec<ActiveSession>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT habit_name, category, started_at, notes, tags
+             FROM active_sessions ORDER BY started_at",
+        )?;
+
+        let sessions = stmt
+            .query_map([], session_from_row)?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(sessions)
+    }
+
+    pub fn cancel_session(&self, habit_name: &str) -> SqliteResult<bool> {
+        let conn = self.conn.lock().unwrap();
+        let affected = conn.execute(
+            "DELETE FROM active_sessions WHERE habit_name = ?1",
+            params![habit_name],
+        )?;
+        Ok(affected > 0)
+    }
+
+    // Timer dihapus dan entry disimpan dalam satu transaksi, jadi stop ganda tidak membuat entry dobel
+    pub fn finish_session(
+        &self,
+        habit_name: &str,
+        ended_at: DateTime<Utc>,
+        compliance_level: Option<u8>,
+        notes: Option<String>,
+    ) -> SqliteResult<Option<Habit>> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+
+        let session = tx
+            .query_row(
+                "SELECT habit_name, category, started_at, notes, tags
+                 FROM active_sessions WHERE habit_name = ?1",
+                params![habit_name],
+                session_from_row,
+            )
+            .optional()?;
+        let Some(session) = session else {
+            return Ok(None);
+        };
+
+        let habit = Habit {
+            id: Uuid::new_v4().to_string(),
+            name: session.habit_name,
+            category: Category::from_str(&session.category),
+            timestamp: session.started_at,
+            compliance_level,
+            notes: notes.or(session.notes),
+            tags: session.tags,
+            end_timestamp: Some(ended_at.max(session.started_at)),
+            quantity: None,
+        };
+
+        tx.execute(
+            "DELETE FROM active_sessions WHERE habit_name = ?1",
+            params![habit_name],
+        )?;
+        insert_habit_row(&tx, &habit)?;
+        tx.commit()?;
+
+        Ok(Some(habit))
+    }
+}
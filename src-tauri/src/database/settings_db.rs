@@ -0,0 +1,50 @@
+use rusqlite::{params, Connection, OptionalExtension, Result as SqliteResult};
+
+use crate::database::HabitDatabase;
+use crate::i18n::Locale;
+
+const LOCALE_KEY: &str = "locale";
+
+pub(super) fn create_tables(conn: &Connection) -> SqliteResult<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS app_settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+impl HabitDatabase {
+    pub fn get_setting(&self, key: &str) -> SqliteResult<Option<String>> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT value FROM app_settings WHERE key = ?1",
+            params![key],
+            |row| row.get(0),
+        )
+        .optional()
+    }
+
+    pub fn set_setting(&self, key: &str, value: &str) -> SqliteResult<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO app_settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = ?2",
+            params![key, value],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_locale(&self) -> SqliteResult<Locale> {
+        Ok(self
+            .get_setting(LOCALE_KEY)?
+            .and_then(|code| Locale::from_code(&code))
+            .unwrap_or_default())
+    }
+
+    pub fn set_locale(&self, locale: Locale) -> SqliteResult<()> {
+        self.set_setting(LOCALE_KEY, locale.code())
+    }
+}
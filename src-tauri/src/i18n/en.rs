@@ -0,0 +1,157 @@
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("number.decimal_separator", "."),
+    ("number.group_separator", ","),
+    ("month.1", "January"),
+    ("month.2", "February"),
+    ("month.3", "March"),
+    ("month.4", "April"),
+    ("month.5", "May"),
+    ("month.6", "June"),
+    ("month.7", "July"),
+    ("month.8", "August"),
+    ("month.9", "September"),
+    ("month.10", "October"),
+    ("month.11", "November"),
+    ("month.12", "December"),
+    ("weekday.0", "Monday"),
+    ("weekday.1", "Tuesday"),
+    ("weekday.2", "Wednesday"),
+    ("weekday.3", "Thursday"),
+    ("weekday.4", "Friday"),
+    ("weekday.5", "Saturday"),
+    ("weekday.6", "Sunday"),
+    ("category.health", "Health"),
+    ("category.productivity", "Productivity"),
+    ("category.hygiene", "Hygiene"),
+    ("category.exercise", "Exercise"),
+    ("category.sleep", "Sleep"),
+    ("category.other", "Other"),
+    ("period.month", "this month"),
+    ("period.week", "this week"),
+    ("period.other", "this period"),
+    ("time_of_day.morning", "morning"),
+    ("time_of_day.afternoon", "afternoon"),
+    ("time_of_day.evening", "evening"),
+    ("time_of_day.late_night", "late night"),
+    ("subject.category", "the {category} category"),
+    ("subject.overall", "all habits"),
+    (
+        "insight.consistency.high",
+        "Excellent consistency! You were active on {percentage} of the days {period}.",
+    ),
+    (
+        "insight.consistency.medium",
+        "Fairly consistent ({percentage}). Keep improving!",
+    ),
+    (
+        "insight.consistency.low",
+        "Consistency is still low ({percentage}). Try setting a daily reminder.",
+    ),
+    (
+        "insight.peak_hour",
+        "You are most active in the {time_of_day} (around {hour}:00).",
+    ),
+    ("insight.peak_weekday", "Your most active day is {weekday}."),
+    (
+        "insight.at_risk.streak",
+        "Your {habit} streak ({streak} days) may break today. Chance of completing: {probability}.",
+    ),
+    (
+        "insight.at_risk.weekday",
+        "You usually do {habit} on {weekday}, but today's chance is only {probability}.",
+    ),
+    (
+        "insight.anomaly.day",
+        "Activity for {subject} on {date} dropped sharply: {observed} versus a usual {baseline}.",
+    ),
+    (
+        "insight.anomaly.week",
+        "Activity for {subject} in the week of {date} dropped sharply: {observed} versus a usual {baseline}.",
+    ),
+    (
+        "insight.neglected_area",
+        "Nothing logged for {category} in {days} days. Try making some time for this area.",
+    ),
+    (
+        "insight.neglected_area.never",
+        "Nothing has been logged for {category} yet. Try making some time for this area.",
+    ),
+    (
+        "insight.personal_record.most_check_ins_day",
+        "New record! {subject} was logged {value} times in one day on {date} (previously {previous}).",
+    ),
+    (
+        "insight.personal_record.best_week_completion",
+        "New record! The week of {date} is your best week for {subject}: active on {value} of days (previously {previous}).",
+    ),
+    (
+        "insight.personal_record.best_month_consistency",
+        "New record! The month starting {date} is your most consistent for {subject}: {value} (previously {previous}).",
+    ),
+    (
+        "insight.personal_record.longest_session",
+        "New record! Longest {subject} session: {value} minutes on {date} (previously {previous} minutes).",
+    ),
+    (
+        "insight.personal_record.largest_quantity",
+        "New record! Largest amount for {subject}: {value} on {date} (previously {previous}).",
+    ),
+    ("rule.consistency", "Share of active days in the period"),
+    ("rule.peak_hour", "Most active hour in the period"),
+    ("rule.peak_weekday", "Most active weekday in the period"),
+    (
+        "rule.at_risk",
+        "Warnings for habits at risk of being skipped today",
+    ),
+    ("rule.anomaly", "Unusual drops in activity"),
+    ("rule.neglected_area", "Life areas (categories) left untouched for a while"),
+    ("report.title", "Monthly report"),
+    ("report.generated", "Generated {date}"),
+    ("report.total_entries", "Total entries"),
+    ("report.consistency", "Consistency"),
+    ("report.most_common_category", "Most common category"),
+    ("report.heatmap", "Daily activity"),
+    ("report.categories", "Entries per category"),
+    ("report.weekly", "Weekly summary"),
+    ("report.week", "Week"),
+    ("report.total", "Total"),
+    ("report.trend", "Trend"),
+    ("report.trend.up", "Up"),
+    ("report.trend.down", "Down"),
+    ("report.trend.stable", "Stable"),
+    ("report.trend.insufficient_data", "Not enough data"),
+    ("report.insights", "Insights"),
+    ("report.no_data", "No entries this month."),
+    ("report.no_insights", "No insights for this month."),
+    ("chart.weekly_title", "Activity {start} – {end}"),
+    ("chart.strength_title", "Habit strength"),
+    ("chart.daily", "Daily"),
+    ("chart.rolling_7", "7-day average"),
+    ("chart.rolling_30", "30-day average"),
+    ("chart.no_data", "No data yet"),
+    ("chart.streak", "day streak"),
+    ("achievement.first_check_in.title", "First Step"),
+    ("achievement.first_check_in.description", "Logged your first activity."),
+    ("achievement.streak_7.title", "Full Week"),
+    ("achievement.streak_7.description", "A 7-day streak."),
+    ("achievement.streak_30.title", "Consistent Month"),
+    ("achievement.streak_30.description", "A 30-day streak."),
+    ("achievement.streak_100.title", "Hundred Days"),
+    ("achievement.streak_100.description", "A 100-day streak."),
+    ("achievement.check_ins_100.title", "Hundred Entries"),
+    ("achievement.check_ins_100.description", "Logged 100 activities."),
+    ("achievement.check_ins_1000.title", "Thousand Entries"),
+    ("achievement.check_ins_1000.description", "Logged 1000 activities."),
+    ("achievement.perfect_week.title", "Perfect Week"),
+    ("achievement.perfect_week.description", "Active every day from Monday to Sunday."),
+    ("achievement.perfect_month.title", "Perfect Month"),
+    ("achievement.perfect_month.description", "Active every day for a whole month."),
+    ("achievement.early_bird_week.title", "Early Bird"),
+    (
+        "achievement.early_bird_week.description",
+        "Logged an activity before 7 AM every day for a week.",
+    ),
+    ("achievement.all_rounder.title", "All-Rounder"),
+    ("achievement.all_rounder.description", "Logged activities in 5 different categories."),
+    ("sleep.default_name", "Sleep"),
+];
@@ -0,0 +1,40 @@
+use crate::i18n::{text, Locale};
+
+// Pemisah desimal dan ribuan diambil dari katalog, jadi "1.234,5" untuk id dan "1,234.5" untuk en
+pub fn format_number(locale: Locale, value: f64, decimals: usize) -> String {
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let group_separator = text(locale, "number.group_separator");
+    let mut grouped = String::new();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i).is_multiple_of(3) {
+            grouped.push_str(&group_separator);
+        }
+        grouped.push(digit);
+    }
+
+    if let Some(fraction) = fraction {
+        grouped.push_str(&text(locale, "number.decimal_separator"));
+        grouped.push_str(fraction);
+    }
+
+    if value < 0.0 && formatted.chars().any(|c| c != '0' && c != '.') {
+        format!("-{}", grouped)
+    } else {
+        grouped
+    }
+}
+
+pub fn format_percent(locale: Locale, value: f64, decimals: usize) -> String {
+    format!("{}%", format_number(locale, value, decimals))
+}
+
+// Bilangan bulat tanpa desimal, selain itu satu angka di belakang koma
+pub fn format_quantity(locale: Locale, value: f64) -> String {
+    let decimals = if value.fract() == 0.0 { 0 } else { 1 };
+    format_number(locale, value, decimals)
+}
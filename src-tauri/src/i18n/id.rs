@@ -0,0 +1,163 @@
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("number.decimal_separator", ","),
+    ("number.group_separator", "."),
+    ("month.1", "Januari"),
+    ("month.2", "Februari"),
+    ("month.3", "Maret"),
+    ("month.4", "April"),
+    ("month.5", "Mei"),
+    ("month.6", "Juni"),
+    ("month.7", "Juli"),
+    ("month.8", "Agustus"),
+    ("month.9", "September"),
+    ("month.10", "Oktober"),
+    ("month.11", "November"),
+    ("month.12", "Desember"),
+    ("weekday.0", "Senin"),
+    ("weekday.1", "Selasa"),
+    ("weekday.2", "Rabu"),
+    ("weekday.3", "Kamis"),
+    ("weekday.4", "Jumat"),
+    ("weekday.5", "Sabtu"),
+    ("weekday.6", "Minggu"),
+    ("category.health", "Kesehatan"),
+    ("category.productivity", "Produktivitas"),
+    ("category.hygiene", "Kebersihan"),
+    ("category.exercise", "Olahraga"),
+    ("category.sleep", "Tidur"),
+    ("category.other", "Lainnya"),
+    ("period.month", "bulan ini"),
+    ("period.week", "minggu ini"),
+    ("period.other", "periode ini"),
+    ("time_of_day.morning", "pagi"),
+    ("time_of_day.afternoon", "siang/sore"),
+    ("time_of_day.evening", "malam"),
+    ("time_of_day.late_night", "larut malam"),
+    ("subject.category", "kategori {category}"),
+    ("subject.overall", "keseluruhan"),
+    (
+        "insight.consistency.high",
+        "Konsistensi sangat baik! Kamu aktif {percentage} dari hari dalam {period}.",
+    ),
+    (
+        "insight.consistency.medium",
+        "Konsistensi cukup baik ({percentage}). Terus tingkatkan!",
+    ),
+    (
+        "insight.consistency.low",
+        "Konsistensi masih rendah ({percentage}). Coba buat pengingat harian.",
+    ),
+    (
+        "insight.peak_hour",
+        "Kamu paling aktif di waktu {time_of_day} (sekitar jam {hour}).",
+    ),
+    (
+        "insight.peak_weekday",
+        "Hari paling aktif adalah {weekday}.",
+    ),
+    (
+        "insight.at_risk.streak",
+        "Streak {habit} ({streak} hari) berisiko putus hari ini. Peluang selesai {probability}.",
+    ),
+    (
+        "insight.at_risk.weekday",
+        "{habit} biasanya dilakukan hari {weekday}, tapi peluang hari ini hanya {probability}.",
+    ),
+    (
+        "insight.anomaly.day",
+        "Aktivitas {subject} pada {date} turun tajam: {observed} dibanding biasanya {baseline}.",
+    ),
+    (
+        "insight.anomaly.week",
+        "Aktivitas {subject} minggu {date} turun tajam: {observed} dibanding biasanya {baseline}.",
+    ),
+    (
+        "insight.neglected_area",
+        "Area {category} belum disentuh selama {days} hari. Coba sisihkan waktu untuk area ini.",
+    ),
+    (
+        "insight.neglected_area.never",
+        "Area {category} belum pernah dicatat. Coba sisihkan waktu untuk area ini.",
+    ),
+    (
+        "insight.personal_record.most_check_ins_day",
+        "Rekor baru! {subject} dicatat {value} kali dalam sehari pada {date} (sebelumnya {previous}).",
+    ),
+    (
+        "insight.personal_record.best_week_completion",
+        "Rekor baru! Minggu {date} jadi minggu terbaik {subject}: aktif {value} hari (sebelumnya {previous}).",
+    ),
+    (
+        "insight.personal_record.best_month_consistency",
+        "Rekor baru! Bulan {date} jadi bulan paling konsisten untuk {subject}: {value} (sebelumnya {previous}).",
+    ),
+    (
+        "insight.personal_record.longest_session",
+        "Rekor baru! Sesi {subject} terpanjang: {value} menit pada {date} (sebelumnya {previous} menit).",
+    ),
+    (
+        "insight.personal_record.largest_quantity",
+        "Rekor baru! Jumlah terbesar untuk {subject}: {value} pada {date} (sebelumnya {previous}).",
+    ),
+    ("rule.consistency", "Persentase hari aktif dalam periode"),
+    ("rule.peak_hour", "Jam paling aktif dalam periode"),
+    ("rule.peak_weekday", "Hari paling aktif dalam periode"),
+    (
+        "rule.at_risk",
+        "Peringatan habit yang berisiko tidak dikerjakan hari ini",
+    ),
+    ("rule.anomaly", "Penurunan aktivitas yang tidak biasa"),
+    (
+        "rule.neglected_area",
+        "Area hidup (kategori) yang lama tidak disentuh",
+    ),
+    ("report.title", "Laporan bulanan"),
+    ("report.generated", "Dibuat {date}"),
+    ("report.total_entries", "Total entry"),
+    ("report.consistency", "Konsistensi"),
+    ("report.most_common_category", "Kategori terbanyak"),
+    ("report.heatmap", "Aktivitas harian"),
+    ("report.categories", "Entry per kategori"),
+    ("report.weekly", "Ringkasan mingguan"),
+    ("report.week", "Minggu"),
+    ("report.total", "Total"),
+    ("report.trend", "Tren"),
+    ("report.trend.up", "Naik"),
+    ("report.trend.down", "Turun"),
+    ("report.trend.stable", "Stabil"),
+    ("report.trend.insufficient_data", "Data belum cukup"),
+    ("report.insights", "Insight"),
+    ("report.no_data", "Belum ada entry bulan ini."),
+    ("report.no_insights", "Belum ada insight bulan ini."),
+    ("chart.weekly_title", "Aktivitas {start} – {end}"),
+    ("chart.strength_title", "Kekuatan kebiasaan"),
+    ("chart.daily", "Harian"),
+    ("chart.rolling_7", "Rata-rata 7 hari"),
+    ("chart.rolling_30", "Rata-rata 30 hari"),
+    ("chart.no_data", "Belum ada data"),
+    ("chart.streak", "hari beruntun"),
+    ("achievement.first_check_in.title", "Langkah Pertama"),
+    ("achievement.first_check_in.description", "Mencatat aktivitas pertama."),
+    ("achievement.streak_7.title", "Seminggu Penuh"),
+    ("achievement.streak_7.description", "Streak 7 hari berturut-turut."),
+    ("achievement.streak_30.title", "Sebulan Konsisten"),
+    ("achievement.streak_30.description", "Streak 30 hari berturut-turut."),
+    ("achievement.streak_100.title", "Seratus Hari"),
+    ("achievement.streak_100.description", "Streak 100 hari berturut-turut."),
+    ("achievement.check_ins_100.title", "Seratus Catatan"),
+    ("achievement.check_ins_100.description", "Mencatat 100 aktivitas."),
+    ("achievement.check_ins_1000.title", "Seribu Catatan"),
+    ("achievement.check_ins_1000.description", "Mencatat 1000 aktivitas."),
+    ("achievement.perfect_week.title", "Minggu Sempurna"),
+    ("achievement.perfect_week.description", "Aktif setiap hari dari Senin sampai Minggu."),
+    ("achievement.perfect_month.title", "Bulan Sempurna"),
+    ("achievement.perfect_month.description", "Aktif setiap hari selama satu bulan penuh."),
+    ("achievement.early_bird_week.title", "Si Burung Pagi"),
+    (
+        "achievement.early_bird_week.description",
+        "Mencatat aktivitas sebelum jam 7 pagi setiap hari selama seminggu.",
+    ),
+    ("achievement.all_rounder.title", "Serba Bisa"),
+    ("achievement.all_rounder.description", "Mencatat aktivitas di 5 kategori berbeda."),
+    ("sleep.default_name", "Tidur"),
+];
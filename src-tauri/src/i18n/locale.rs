@@ -0,0 +1,79 @@
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{en, id};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    Id,
+    En,
+}
+
+impl Locale {
+    pub const ALL: &'static [Locale] = &[Locale::Id, Locale::En];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::Id => "id",
+            Locale::En => "en",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.to_lowercase();
+        // "en-US" dan "id-ID" tetap dikenali dari bagian bahasanya
+        let language = code.split(['-', '_']).next().unwrap_or_default();
+        Self::ALL.iter().copied().find(|l| l.code() == language)
+    }
+
+    fn messages(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::Id => id::MESSAGES,
+            Locale::En => en::MESSAGES,
+        }
+    }
+}
+
+// Key yang belum diterjemahkan jatuh ke locale default, lalu ke key itu sendiri
+pub fn text(locale: Locale, key: &str) -> String {
+    let lookup = |l: Locale| {
+        l.messages()
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| *v)
+    };
+    lookup(locale)
+        .or_else(|| lookup(Locale::default()))
+        .unwrap_or(key)
+        .to_string()
+}
+
+// Placeholder berbentuk {nama} diganti dengan nilai dari args
+pub fn tr(locale: Locale, key: &str, args: &[(&str, String)]) -> String {
+    let mut message = text(locale, key);
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), value);
+    }
+    message
+}
+
+pub fn month_name(locale: Locale, month: u32) -> String {
+    if (1..=12).contains(&month) {
+        text(locale, &format!("month.{}", month))
+    } else {
+        "Unknown".to_string()
+    }
+}
+
+pub fn weekday_name(locale: Locale, weekday: Weekday) -> String {
+    text(
+        locale,
+        &format!("weekday.{}", weekday.num_days_from_monday()),
+    )
+}
+
+pub fn category_label(locale: Locale, category: &str) -> String {
+    text(locale, &format!("category.{}", category))
+}
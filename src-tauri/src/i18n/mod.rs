@@ -0,0 +1,7 @@
+pub mod en;
+pub mod format;
+pub mod id;
+pub mod locale;
+
+pub use format::*;
+pub use locale::*;
@@ -1,14 +1,27 @@
+pub mod charts;
 pub mod commands;
 pub mod database;
+pub mod i18n;
 pub mod models;
 pub mod statistics;
+pub mod transfer;
 
 use tauri::Manager;
 
 use commands::{
-    add_habit, delete_habit, get_all_habits, get_categories, get_current_streak, get_daily_stats,
-    get_habit_count, get_habits_by_category, get_habits_by_date_range, get_monthly_stats,
-    get_this_week_habits, get_today_habits, get_weekly_stats, AppState,
+    add_goal, add_habit, cancel_session, delete_goal, delete_habit, delete_habit_scale,
+    dismiss_insight, export_backup, export_chart, export_csv, export_ics, export_markdown_journal,
+    export_monthly_report_pdf, get_achievements, get_active_sessions, get_all_habits, get_anomalies,
+    get_at_risk_habits, get_available_locales, get_balance_report, get_balance_targets,
+    get_categories, get_compliance_report, get_current_streak, get_daily_stats, get_duration_report,
+    get_goal_history, get_goal_progress, get_goals, get_habit_correlations, get_habit_count,
+    get_habit_scales, get_habits_by_category, get_habits_by_date_range, get_insight_rules,
+    get_insights, get_locale, get_monthly_stats, get_personal_records, get_series, get_sleep_report,
+    get_sleep_target, get_this_week_habits, get_time_of_day_stats, get_today_habits, get_trend,
+    get_weekly_stats, import_backup, import_csv, import_habitica, import_ics, import_loop_backup,
+    log_sleep, mark_insights_shown, preview_csv_import, render_chart, reset_balance_targets,
+    restore_insight, set_balance_target, set_habit_scale, set_locale, set_sleep_target,
+    start_session, stop_session, update_goal, update_insight_rule, AppState,
 };
 use database::HabitDatabase;
 
@@ -42,6 +55,59 @@ pub fn run() {
             get_this_week_habits,
             get_habit_count,
             get_categories,
+            get_habit_correlations,
+            get_trend,
+            get_compliance_report,
+            set_habit_scale,
+            get_habit_scales,
+            delete_habit_scale,
+            get_time_of_day_stats,
+            get_at_risk_habits,
+            get_anomalies,
+            add_goal,
+            update_goal,
+            get_goals,
+            delete_goal,
+            get_goal_progress,
+            get_goal_history,
+            get_achievements,
+            get_insights,
+            dismiss_insight,
+            restore_insight,
+            get_insight_rules,
+            update_insight_rule,
+            get_locale,
+            set_locale,
+            get_available_locales,
+            log_sleep,
+            get_sleep_report,
+            get_sleep_target,
+            set_sleep_target,
+            start_session,
+            stop_session,
+            cancel_session,
+            get_active_sessions,
+            get_duration_report,
+            get_balance_report,
+            get_balance_targets,
+            set_balance_target,
+            reset_balance_targets,
+            get_series,
+            get_personal_records,
+            export_backup,
+            import_backup,
+            export_csv,
+            preview_csv_import,
+            import_csv,
+            import_loop_backup,
+            import_habitica,
+            export_ics,
+            import_ics,
+            export_markdown_journal,
+            export_monthly_report_pdf,
+            render_chart,
+            export_chart,
+            mark_insights_shown,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
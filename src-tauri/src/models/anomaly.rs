@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AnomalyGranularity {
+    Day,
+    Week,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AnomalyDirection {
+    Dip,
+    Spike,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnomalyEvent {
+    // Keduanya None = seluruh aktivitas
+    pub habit_name: Option<String>,
+    pub category: Option<String>,
+    pub granularity: AnomalyGranularity,
+    pub timestamp: DateTime<Utc>,
+    pub period_start: String,
+    pub period_end: String,
+    pub observed: f64,
+    pub baseline_median: f64,
+    pub robust_z: f64,
+    pub direction: AnomalyDirection,
+    pub severity: Severity,
+}
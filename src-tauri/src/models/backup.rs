@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::models::{Goal, Habit, InsightRuleSettings, RatingScale};
+
+pub const BACKUP_FORMAT: &str = "habit-tracker-backup";
+// Naikkan setiap kali struktur BackupData berubah secara tidak kompatibel
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+// Isi database yang ikut dibackup. insight_log dan sesi timer yang sedang berjalan
+// sengaja tidak ikut karena hanya berlaku di perangkat asal.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupData {
+    #[serde(default)]
+    pub habits: Vec<Habit>,
+    #[serde(default)]
+    pub goals: Vec<Goal>,
+    #[serde(default)]
+    pub habit_scales: BTreeMap<String, RatingScale>,
+    #[serde(default)]
+    pub achievements: BTreeMap<String, DateTime<Utc>>,
+    #[serde(default)]
+    pub insight_settings: Vec<InsightRuleSettings>,
+    #[serde(default)]
+    pub insight_dismissals: BTreeMap<String, DateTime<Utc>>,
+    #[serde(default)]
+    pub settings: BTreeMap<String, String>,
+    #[serde(default)]
+    pub balance_targets: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupDocument {
+    pub format: String,
+    pub format_version: u32,
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub data: BackupData,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportMode {
+    // Data lokal dipertahankan, hanya id yang belum ada yang ditambahkan
+    #[default]
+    Merge,
+    // Seluruh data lokal diganti isi backup
+    Replace,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportCounts {
+    pub added: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    // Mode merge: id sudah ada dengan isi berbeda, data lokal yang dipakai
+    pub skipped: usize,
+    pub removed: usize,
+    // Id yang muncul lebih dari sekali di file, hanya yang terakhir dipakai
+    pub duplicates: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSummary {
+    pub mode: ImportMode,
+    pub dry_run: bool,
+    pub format_version: u32,
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    pub habits: ImportCounts,
+    pub goals: ImportCounts,
+    pub habit_scales: ImportCounts,
+    pub achievements: ImportCounts,
+    pub insight_settings: ImportCounts,
+    pub insight_dismissals: ImportCounts,
+    pub settings: ImportCounts,
+    pub balance_targets: ImportCounts,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupExportResult {
+    pub path: String,
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub habit_count: usize,
+    pub goal_count: usize,
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::Insight;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceTarget {
+    pub category: String,
+    // Bobot relatif, dinormalisasi jadi porsi saat laporan dibuat
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryBalance {
+    pub category: String,
+    pub count: usize,
+    pub actual_share: f64,
+    pub target_share: f64,
+    // Positif = lebih banyak dari target
+    pub deviation: f64,
+    pub days_since_last: Option<i64>,
+    pub neglected: bool,
+}
+
+// Urutan labels sama dengan actual dan target, langsung bisa dipakai chart radar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceRadar {
+    pub labels: Vec<String>,
+    pub actual: Vec<f64>,
+    pub target: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceReport {
+    pub start: String,
+    pub end: String,
+    pub total: usize,
+    // 100 = porsi aktual persis sama dengan target
+    pub score: f64,
+    pub neglect_days: i64,
+    pub categories: Vec<CategoryBalance>,
+    pub radar: BalanceRadar,
+    pub insights: Vec<Insight>,
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::SeriesFilter;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartKind {
+    WeeklyBars,
+    MonthlyHeatmap,
+    // Rata-rata bergulir 7/30 hari, sama dengan grafik series di StatisticsChart.vue
+    StrengthLine,
+    CategoryPie,
+    // Kartu persegi untuk dibagikan: streak dan konsistensi bulan ini
+    ShareCard,
+}
+
+impl ChartKind {
+    pub fn default_size(&self) -> ChartSize {
+        match self {
+            ChartKind::ShareCard => ChartSize::Square,
+            _ => ChartSize::Medium,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartSize {
+    Small,
+    Medium,
+    Large,
+    Square,
+}
+
+impl ChartSize {
+    // Ukuran dalam piksel, rasio 16:9 kecuali Square
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            ChartSize::Small => (480, 270),
+            ChartSize::Medium => (800, 450),
+            ChartSize::Large => (1200, 675),
+            ChartSize::Square => (1080, 1080),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartFormat {
+    #[default]
+    Svg,
+    Png,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartThemeName {
+    #[default]
+    Light,
+    Dark,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartOptions {
+    pub kind: ChartKind,
+    #[serde(default)]
+    pub format: ChartFormat,
+    // Kosong = ukuran bawaan jenis grafik
+    #[serde(default)]
+    pub size: Option<ChartSize>,
+    #[serde(default)]
+    pub theme: ChartThemeName,
+    #[serde(default)]
+    pub locale: Option<String>,
+    // weekly_bars, kosong = minggu ini
+    #[serde(default)]
+    pub week_start: Option<String>,
+    // monthly_heatmap, category_pie dan share_card, kosong = bulan ini
+    #[serde(default)]
+    pub year: Option<i32>,
+    #[serde(default)]
+    pub month: Option<u32>,
+    // strength_line, kosong = 90 hari terakhir
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub end_date: Option<String>,
+    #[serde(default, flatten)]
+    pub filter: SeriesFilter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartExportResult {
+    pub path: String,
+    pub format: ChartFormat,
+    pub width: u32,
+    pub height: u32,
+}
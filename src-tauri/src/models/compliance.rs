@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::TrendAnalysis;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RatingScale {
+    Numeric { min: u8, max: u8 },
+    YesNo,
+    // Nilai 1..=labels.len(), label ke-i untuk nilai i
+    Emoji { labels: Vec<String> },
+}
+
+impl Default for RatingScale {
+    fn default() -> Self {
+        RatingScale::Numeric { min: 1, max: 5 }
+    }
+}
+
+impl RatingScale {
+    pub fn bounds(&self) -> (u8, u8) {
+        match self {
+            RatingScale::Numeric { min, max } => (*min, *max),
+            RatingScale::YesNo => (0, 1),
+            RatingScale::Emoji { labels } => (1, labels.len().min(u8::MAX as usize) as u8),
+        }
+    }
+
+    pub fn is_valid(&self) -> Result<(), String> {
+        match self {
+            RatingScale::Numeric { min, max } if min >= max => Err(format!(
+                "Invalid scale: min ({}) must be below max ({})",
+                min, max
+            )),
+            RatingScale::Emoji { labels } if labels.len() < 2 => {
+                Err("Invalid scale: emoji scale needs at least 2 labels".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate(&self, level: u8) -> Result<(), String> {
+        let (min, max) = self.bounds();
+        if level < min || level > max {
+            return Err(format!(
+                "Compliance level {} is outside the scale ({}-{})",
+                level, min, max
+            ));
+        }
+        Ok(())
+    }
+
+    // Nilai 0.0-1.0 supaya habit dengan skala berbeda bisa digabung
+    pub fn normalize(&self, level: u8) -> f64 {
+        let (min, max) = self.bounds();
+        if max <= min {
+            return 0.0;
+        }
+        let clamped = level.clamp(min, max);
+        (clamped - min) as f64 / (max - min) as f64
+    }
+
+    pub fn label(&self, level: u8) -> String {
+        match self {
+            RatingScale::Numeric { .. } => level.to_string(),
+            RatingScale::YesNo => if level > 0 { "yes" } else { "no" }.to_string(),
+            RatingScale::Emoji { labels } => labels
+                .get((level as usize).wrapping_sub(1))
+                .cloned()
+                .unwrap_or_else(|| level.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HabitScale {
+    pub habit_name: String,
+    pub scale: RatingScale,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceBucket {
+    pub value: u8,
+    pub label: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceHistogram {
+    pub habit_name: String,
+    pub scale: RatingScale,
+    pub buckets: Vec<ComplianceBucket>,
+    pub total: usize,
+    pub average: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceBreakdown {
+    pub key: String,
+    pub count: usize,
+    // Rata-rata ternormalisasi 0-100 lintas skala
+    pub average_percentage: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceReport {
+    pub start_date: String,
+    pub end_date: String,
+    pub histograms: Vec<ComplianceHistogram>,
+    pub trend: TrendAnalysis,
+    pub by_category: Vec<ComplianceBreakdown>,
+    pub by_weekday: Vec<ComplianceBreakdown>,
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum SubjectKind {
+    Habit,
+    Category,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CorrelationSubject {
+    pub kind: SubjectKind,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrelationFinding {
+    pub subject_a: CorrelationSubject,
+    pub subject_b: CorrelationSubject,
+    // 0 = hari yang sama, 1 = subject_b dicek pada hari berikutnya
+    pub lag_days: u32,
+    pub sample_days: usize,
+    pub a_days: usize,
+    pub b_days: usize,
+    pub both_days: usize,
+    pub rate_with_a: f64,
+    pub rate_without_a: f64,
+    pub phi: f64,
+    pub pearson: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrelationReport {
+    pub start_date: String,
+    pub end_date: String,
+    pub sample_days: usize,
+    pub min_samples: usize,
+    pub pairs_tested: usize,
+    pub pairs_skipped: usize,
+    pub findings: Vec<CorrelationFinding>,
+}
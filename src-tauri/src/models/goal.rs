@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::Habit;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum GoalPeriod {
+    Week,
+    Month,
+    Year,
+    Custom,
+}
+
+impl GoalPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalPeriod::Week => "week",
+            GoalPeriod::Month => "month",
+            GoalPeriod::Year => "year",
+            GoalPeriod::Custom => "custom",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "week" => GoalPeriod::Week,
+            "year" => GoalPeriod::Year,
+            "custom" => GoalPeriod::Custom,
+            _ => GoalPeriod::Month,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum GoalScope {
+    Habit(String),
+    Category(String),
+    Tag(String),
+}
+
+impl GoalScope {
+    pub fn parts(&self) -> (&'static str, &str) {
+        match self {
+            GoalScope::Habit(value) => ("habit", value),
+            GoalScope::Category(value) => ("category", value),
+            GoalScope::Tag(value) => ("tag", value),
+        }
+    }
+
+    pub fn from_parts(kind: &str, value: String) -> Self {
+        match kind {
+            "category" => GoalScope::Category(value),
+            "tag" => GoalScope::Tag(value),
+            _ => GoalScope::Habit(value),
+        }
+    }
+
+    pub fn matches(&self, habit: &Habit) -> bool {
+        match self {
+            GoalScope::Habit(name) => habit.name.eq_ignore_ascii_case(name),
+            GoalScope::Category(category) => habit.category.as_str() == category.to_lowercase(),
+            GoalScope::Tag(tag) => habit.tags.iter().any(|t| t == &tag.to_lowercase()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalMetric {
+    // Jumlah entry, mis. "olahraga 4x per minggu"
+    Count,
+    // Jumlah hari berbeda yang ada entry-nya
+    ActiveDays,
+    // Total menit dari entry yang punya durasi (sesi timer)
+    Minutes,
+}
+
+impl GoalMetric {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalMetric::Count => "count",
+            GoalMetric::ActiveDays => "active_days",
+            GoalMetric::Minutes => "minutes",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "active_days" => GoalMetric::ActiveDays,
+            "minutes" => GoalMetric::Minutes,
+            _ => GoalMetric::Count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Goal {
+    pub id: String,
+    pub title: String,
+    pub scope: GoalScope,
+    pub metric: GoalMetric,
+    pub target: f64,
+    pub period: GoalPeriod,
+    // Hanya dipakai untuk GoalPeriod::Custom (format YYYY-MM-DD)
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalInput {
+    pub title: String,
+    pub scope: GoalScope,
+    pub metric: GoalMetric,
+    pub target: f64,
+    pub period: GoalPeriod,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalProgress {
+    pub goal: Goal,
+    pub period_start: String,
+    pub period_end: String,
+    pub current: f64,
+    pub percentage: f64,
+    // Perkiraan nilai di akhir periode kalau kecepatan sekarang dipertahankan
+    pub projected: f64,
+    pub on_track: bool,
+    pub met: bool,
+    pub days_elapsed: i64,
+    pub days_total: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalPeriodResult {
+    pub period_start: String,
+    pub period_end: String,
+    pub achieved: f64,
+    pub target: f64,
+    pub met: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalHistory {
+    pub goal: Goal,
+    pub periods: Vec<GoalPeriodResult>,
+    pub met_count: usize,
+    pub missed_count: usize,
+}
@@ -1,5 +1,8 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::models::Severity;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Habit {
@@ -9,6 +12,33 @@ pub struct Habit {
     pub timestamp: DateTime<Utc>,
     pub compliance_level: Option<u8>,
     pub notes: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    // Hanya untuk entry yang punya rentang waktu, misalnya tidur (timestamp = jam tidur)
+    #[serde(default)]
+    pub end_timestamp: Option<DateTime<Utc>>,
+    // Jumlah terukur, misalnya gelas air atau halaman buku
+    #[serde(default)]
+    pub quantity: Option<f64>,
+}
+
+impl Habit {
+    pub fn duration_minutes(&self) -> Option<f64> {
+        self.end_timestamp
+            .map(|end| (end - self.timestamp).num_seconds() as f64 / 60.0)
+    }
+}
+
+// Tag disimpan lowercase tanpa duplikat supaya filter goal/statistik konsisten
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = tags
+        .iter()
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    normalized.sort();
+    normalized.dedup();
+    normalized
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -23,6 +53,15 @@ pub enum Category {
 }
 
 impl Category {
+    pub const ALL: [Category; 6] = [
+        Category::Health,
+        Category::Productivity,
+        Category::Hygiene,
+        Category::Exercise,
+        Category::Sleep,
+        Category::Other,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Category::Health => "health",
@@ -53,6 +92,12 @@ pub struct HabitInput {
     pub timestamp: Option<String>,
     pub compliance_level: Option<u8>,
     pub notes: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub end_timestamp: Option<String>,
+    #[serde(default)]
+    pub quantity: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,6 +122,7 @@ pub struct WeeklyStats {
     pub total_habits: usize,
     pub most_active_day: String,
     pub trend: Trend,
+    pub trend_analysis: TrendAnalysis,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,7 +133,7 @@ pub struct MonthlyStats {
     pub total_habits: usize,
     pub consistency_percentage: f64,
     pub most_common_category: String,
-    pub insights: Vec<String>,
+    pub insights: Vec<Insight>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -96,12 +142,85 @@ pub enum Trend {
     Up,
     Down,
     Stable,
+    #[serde(rename = "insufficient_data")]
+    InsufficientData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendAnalysis {
+    pub direction: Trend,
+    // Kemiringan least-squares, satuan metrik per hari
+    pub slope: f64,
+    // 1 - p-value dari uji Mann-Kendall (dua sisi)
+    pub confidence: f64,
+    pub kendall_tau: f64,
+    pub sample_size: usize,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendMetric {
+    #[serde(alias = "count")]
+    DailyCount,
+    Compliance,
+    CompletionRate,
+    // Total menit dari entry yang punya durasi
+    Duration,
+    Quantity,
+}
+
+impl TrendMetric {
+    // Metrik berupa jumlah boleh dijumlahkan per minggu/bulan; rasio dan rata-rata tidak
+    pub fn is_additive(&self) -> bool {
+        matches!(
+            self,
+            TrendMetric::DailyCount | TrendMetric::Duration | TrendMetric::Quantity
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Insight {
+    // Stabil untuk periode yang sama, dipakai untuk dismiss dan cooldown
+    pub id: String,
+    pub rule_id: String,
+    // Dibangun dari metrics dan periode, lihat statistics::describe_insight
     pub message: String,
     pub insight_type: InsightType,
+    pub severity: Severity,
+    pub metrics: BTreeMap<String, f64>,
+    pub period_start: String,
+    pub period_end: String,
+    pub habit_names: Vec<String>,
+    pub categories: Vec<String>,
+}
+
+impl Insight {
+    pub fn new(
+        id: String,
+        rule_id: &str,
+        insight_type: InsightType,
+        severity: Severity,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Self {
+        Self {
+            id,
+            rule_id: rule_id.to_string(),
+            message: String::new(),
+            insight_type,
+            severity,
+            metrics: BTreeMap::new(),
+            period_start: period_start.to_string(),
+            period_end: period_end.to_string(),
+            habit_names: Vec::new(),
+            categories: Vec::new(),
+        }
+    }
+
+    pub fn metric(&self, key: &str) -> f64 {
+        self.metrics.get(key).copied().unwrap_or(0.0)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InsightRuleSettings {
+    pub rule_id: String,
+    pub enabled: bool,
+    pub priority: i32,
+    pub cooldown_days: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InsightRuleInfo {
+    pub rule_id: String,
+    pub description: String,
+    pub settings: InsightRuleSettings,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShownInsight {
+    pub insight_id: String,
+    pub rule_id: String,
+    pub first_shown_at: DateTime<Utc>,
+}
+
+// Pengaturan pengguna yang disimpan di database, dibaca sebelum insight dibuat
+#[derive(Debug, Clone, Default)]
+pub struct InsightPreferences {
+    pub settings: HashMap<String, InsightRuleSettings>,
+    pub dismissed: HashSet<String>,
+    pub shown: Vec<ShownInsight>,
+    // Bobot area hidup dari pengaturan balance, dipakai rule neglected_area
+    pub balance_weights: HashMap<String, f64>,
+}
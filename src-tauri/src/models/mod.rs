@@ -1,3 +1,35 @@
+pub mod achievement;
+pub mod anomaly;
+pub mod backup;
+pub mod balance;
+pub mod chart;
+pub mod compliance;
+pub mod correlation;
+pub mod goal;
 pub mod habit;
+pub mod insight;
+pub mod prediction;
+pub mod record;
+pub mod series;
+pub mod session;
+pub mod sleep;
+pub mod timing;
+pub mod transfer;
 
-pub use habit::*;
\ No newline at end of file
+pub use achievement::*;
+pub use anomaly::*;
+pub use backup::*;
+pub use balance::*;
+pub use chart::*;
+pub use compliance::*;
+pub use correlation::*;
+pub use goal::*;
+pub use habit::*;
+pub use insight::*;
+pub use prediction::*;
+pub use record::*;
+pub use series::*;
+pub use session::*;
+pub use sleep::*;
+pub use timing::*;
+pub use transfer::*;
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::Insight;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionPrediction {
+    pub habit_name: String,
+    pub category: String,
+    pub done_today: bool,
+    pub probability_today: f64,
+    pub probability_tomorrow: f64,
+    pub weekday_rate: f64,
+    pub momentum: f64,
+    pub days_since_last: i64,
+    pub current_streak: usize,
+    pub risk_score: f64,
+    pub at_risk: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AtRiskReport {
+    pub date: String,
+    pub predictions: Vec<CompletionPrediction>,
+    pub at_risk: Vec<CompletionPrediction>,
+    pub insights: Vec<Insight>,
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{CorrelationSubject, Insight};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordKind {
+    MostCheckInsDay,
+    // Persentase hari aktif dalam satu minggu Senin-Minggu
+    BestWeekCompletion,
+    // Persentase hari aktif dalam satu bulan kalender
+    BestMonthConsistency,
+    // Dalam menit
+    LongestSession,
+    LargestQuantity,
+}
+
+impl RecordKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordKind::MostCheckInsDay => "most_check_ins_day",
+            RecordKind::BestWeekCompletion => "best_week_completion",
+            RecordKind::BestMonthConsistency => "best_month_consistency",
+            RecordKind::LongestSession => "longest_session",
+            RecordKind::LargestQuantity => "largest_quantity",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalRecord {
+    pub subject: CorrelationSubject,
+    pub kind: RecordKind,
+    pub value: f64,
+    // Periode pertama kali nilai ini tercapai (hari, minggu atau bulan)
+    pub period_start: String,
+    pub period_end: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewRecord {
+    pub record: PersonalRecord,
+    pub previous_value: f64,
+    pub insight: Insight,
+}
@@ -0,0 +1,85 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Habit, TrendMetric};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeGrouping {
+    Day,
+    Week,
+    Month,
+}
+
+impl TimeGrouping {
+    // Minggu dimulai hari Senin, sama seperti calculate_monthly_stats
+    pub fn period_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            TimeGrouping::Day => date,
+            TimeGrouping::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            TimeGrouping::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    pub fn next_period_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            TimeGrouping::Day => date + Duration::days(1),
+            TimeGrouping::Week => date + Duration::days(7),
+            TimeGrouping::Month => {
+                if date.month() == 12 {
+                    NaiveDate::from_ymd_opt(date.year() + 1, 1, 1).unwrap_or(date)
+                } else {
+                    NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1).unwrap_or(date)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SeriesFilter {
+    pub habit_name: Option<String>,
+    pub category: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl SeriesFilter {
+    pub fn matches(&self, habit: &Habit) -> bool {
+        self.habit_name
+            .as_ref()
+            .is_none_or(|name| &habit.name == name)
+            && self
+                .category
+                .as_ref()
+                .is_none_or(|category| habit.category.as_str() == category.to_lowercase())
+            && self
+                .tag
+                .as_ref()
+                .is_none_or(|tag| habit.tags.contains(&tag.trim().to_lowercase()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesPoint {
+    pub period_start: String,
+    pub period_end: String,
+    // None = tidak ada observasi (mis. tidak ada compliance tercatat)
+    pub value: Option<f64>,
+    // Rata-rata harian 7/30 hari terakhir sampai akhir periode
+    pub rolling_7: Option<f64>,
+    pub rolling_30: Option<f64>,
+    // Hanya untuk metrik yang bisa dijumlahkan (count, duration, quantity)
+    pub cumulative: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesReport {
+    pub metric: TrendMetric,
+    pub grouping: TimeGrouping,
+    pub start: String,
+    pub end: String,
+    pub filter: SeriesFilter,
+    pub points: Vec<SeriesPoint>,
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::TimeGrouping;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionStartInput {
+    pub name: String,
+    pub category: String,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+// Timer yang sedang berjalan, disimpan di database supaya tetap ada setelah aplikasi ditutup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveSession {
+    pub habit_name: String,
+    pub category: String,
+    pub started_at: DateTime<Utc>,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DurationTotal {
+    pub key: String,
+    pub total_minutes: f64,
+    pub sessions: usize,
+    pub average_minutes: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DurationBucket {
+    pub period_start: String,
+    pub period_end: String,
+    pub total_minutes: f64,
+    pub by_habit: Vec<DurationTotal>,
+    pub by_category: Vec<DurationTotal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DurationReport {
+    pub period: TimeGrouping,
+    pub start: String,
+    pub end: String,
+    pub buckets: Vec<DurationBucket>,
+    pub by_habit: Vec<DurationTotal>,
+    pub by_category: Vec<DurationTotal>,
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SleepInput {
+    pub bedtime: String,
+    pub wake_time: String,
+    pub name: Option<String>,
+    pub compliance_level: Option<u8>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SleepNight {
+    // Tanggal bangun (waktu lokal), tidur malam Jumat dihitung ke hari Sabtu
+    pub date: String,
+    pub bedtime: DateTime<Utc>,
+    pub wake_time: DateTime<Utc>,
+    pub duration_hours: f64,
+    pub weekend: bool,
+    // Jumlah habit berbeda (selain tidur) yang dicatat pada tanggal bangun
+    pub next_day_completions: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SleepReport {
+    pub start: String,
+    pub end: String,
+    pub target_hours: f64,
+    pub nights: Vec<SleepNight>,
+    pub average_duration_hours: f64,
+    // Total kekurangan tidur terhadap target, malam yang melebihi target tidak mengurangi
+    pub sleep_debt_hours: f64,
+    pub average_bedtime: Option<String>,
+    pub bedtime_std_minutes: f64,
+    // Selisih titik tengah tidur akhir pekan vs hari kerja
+    pub social_jetlag_hours: Option<f64>,
+    pub next_day_correlation: Option<f64>,
+}
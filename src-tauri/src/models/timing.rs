@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::TrendAnalysis;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeSummary {
+    pub count: usize,
+    // Menit sejak tengah malam (waktu lokal)
+    pub median_minutes: u32,
+    pub median_time: String,
+    // Circular standard deviation dalam jam
+    pub spread_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeDrift {
+    pub trend: TrendAnalysis,
+    // Positif = makin siang/malam, negatif = makin pagi
+    pub total_drift_hours: f64,
+    pub span_weeks: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeOfDayStats {
+    pub habit_name: String,
+    pub hourly_histogram: Vec<usize>,
+    pub overall: TimeSummary,
+    pub weekday: Option<TimeSummary>,
+    pub weekend: Option<TimeSummary>,
+    pub drift: TimeDrift,
+}
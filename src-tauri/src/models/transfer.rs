@@ -0,0 +1,240 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::{Habit, ImportCounts, SeriesFilter};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvColumn {
+    Id,
+    Name,
+    Category,
+    Timestamp,
+    EndTimestamp,
+    DurationMinutes,
+    Compliance,
+    Notes,
+    Tags,
+    Quantity,
+}
+
+impl CsvColumn {
+    pub fn header(&self) -> &'static str {
+        match self {
+            CsvColumn::Id => "id",
+            CsvColumn::Name => "name",
+            CsvColumn::Category => "category",
+            CsvColumn::Timestamp => "timestamp",
+            CsvColumn::EndTimestamp => "end_timestamp",
+            CsvColumn::DurationMinutes => "duration_minutes",
+            CsvColumn::Compliance => "compliance",
+            CsvColumn::Notes => "notes",
+            CsvColumn::Tags => "tags",
+            CsvColumn::Quantity => "quantity",
+        }
+    }
+
+    pub fn defaults() -> Vec<CsvColumn> {
+        vec![
+            CsvColumn::Name,
+            CsvColumn::Category,
+            CsvColumn::Timestamp,
+            CsvColumn::Compliance,
+            CsvColumn::Notes,
+            CsvColumn::Tags,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvExportOptions {
+    #[serde(default = "CsvColumn::defaults")]
+    pub columns: Vec<CsvColumn>,
+    #[serde(default)]
+    pub delimiter: Option<char>,
+    // Format strftime chrono dalam waktu lokal, kosong = RFC 3339
+    #[serde(default)]
+    pub date_format: Option<String>,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IcsExportOptions {
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub end_date: Option<String>,
+    #[serde(default, flatten)]
+    pub filter: SeriesFilter,
+    // Goal aktif diekspor sebagai VTODO berulang (RRULE) sesuai periodenya
+    #[serde(default = "default_include_goals")]
+    pub include_goals: bool,
+}
+
+fn default_include_goals() -> bool {
+    true
+}
+
+// Nilai setiap kolom adalah nama header di file (tidak peka huruf besar)
+// atau nomor kolom mulai dari 1 kalau file tidak punya header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvColumnMapping {
+    pub name: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub compliance: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub tags: Option<String>,
+    // Dicoba lebih dulu sebelum format bawaan
+    #[serde(default)]
+    pub timestamp_format: Option<String>,
+    // Dipakai kalau kolom kategori tidak dipetakan atau kosong
+    #[serde(default)]
+    pub default_category: Option<String>,
+    #[serde(default)]
+    pub delimiter: Option<char>,
+    #[serde(default = "default_has_header")]
+    pub has_header: bool,
+}
+
+fn default_has_header() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvPreviewRow {
+    // Nomor baris data, mulai dari 1 (header tidak dihitung)
+    pub row: usize,
+    pub entry: Option<Habit>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvPreview {
+    pub headers: Vec<String>,
+    pub total_rows: usize,
+    pub valid_rows: usize,
+    pub rows: Vec<CsvPreviewRow>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoopImportOptions {
+    // Kategori untuk habit Loop yang tidak ada di `categories`
+    #[serde(default)]
+    pub default_category: Option<String>,
+    // Nama habit Loop -> kategori
+    #[serde(default)]
+    pub categories: HashMap<String, String>,
+    #[serde(default)]
+    pub skip_archived: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HabiticaImportOptions {
+    // Dipakai kalau tidak ada tag task yang cocok dengan kategori
+    #[serde(default)]
+    pub default_category: Option<String>,
+    // Nama tag Habitica -> kategori; tag yang namanya sama dengan kategori otomatis cocok
+    #[serde(default)]
+    pub tag_categories: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarkdownExportOptions {
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub end_date: Option<String>,
+    // Format strftime nama file, kosong = YYYY-MM-DD seperti daily note Obsidian
+    #[serde(default)]
+    pub file_name_format: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarkdownExportResult {
+    pub folder: String,
+    pub created: usize,
+    // File yang sudah ada, hanya bagian habit dan frontmatter milik kita yang diganti
+    pub updated: usize,
+    pub unchanged: usize,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum IcsMatchField {
+    #[default]
+    Summary,
+    Description,
+    Location,
+    Categories,
+    // Salah satu dari field di atas
+    Any,
+}
+
+// Contoh: summary mengandung "Gym" -> kategori exercise
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IcsImportRule {
+    pub contains: String,
+    #[serde(default)]
+    pub field: IcsMatchField,
+    pub category: String,
+    // Kosong = pakai SUMMARY event sebagai nama habit
+    #[serde(default)]
+    pub habit_name: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IcsImportOptions {
+    pub rules: Vec<IcsImportRule>,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    // Kosong = sampai hari ini; event yang belum terjadi tidak diimport
+    #[serde(default)]
+    pub end_date: Option<String>,
+}
+
+// Ringkasan per habit sumber, untuk importer dari aplikasi lain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedHabitSummary {
+    pub source_name: String,
+    pub name: String,
+    pub category: String,
+    pub entries: usize,
+    // Penanda yang tidak punya padanan di model kita, misalnya skip di Loop
+    pub ignored: usize,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportRowError {
+    pub row: usize,
+    pub message: String,
+}
+
+// Ringkasan semua importer entry. counts.skipped = sudah ada di database,
+// counts.duplicates = muncul lebih dari sekali di file sumber
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryImportReport {
+    pub source: String,
+    pub dry_run: bool,
+    pub total_rows: usize,
+    pub counts: ImportCounts,
+    pub errors: Vec<ImportRowError>,
+    pub warnings: Vec<String>,
+    #[serde(default)]
+    pub habits: Vec<ImportedHabitSummary>,
+    // Hanya diisi saat dry run, beberapa entry pertama hasil parse
+    #[serde(default)]
+    pub preview: Vec<Habit>,
+}
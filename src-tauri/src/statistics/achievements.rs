@@ -0,0 +1,175 @@
+use chrono::{Datelike, Duration, Local, NaiveDate, Timelike};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::i18n::{text, Locale};
+use crate::models::Habit;
+
+pub enum AchievementRule {
+    // Streak terpanjang (hari berturut-turut dengan minimal satu entry)
+    Streak(usize),
+    TotalCheckIns(usize),
+    // Satu minggu Senin-Minggu penuh aktif setiap hari
+    PerfectWeek,
+    // Satu bulan kalender penuh aktif setiap hari
+    PerfectMonth,
+    // Satu minggu Senin-Minggu dengan entry sebelum jam tertentu setiap hari
+    EarlyBirdWeek { before_hour: u32 },
+    DistinctCategories(usize),
+}
+
+pub struct AchievementDefinition {
+    pub id: &'static str,
+    pub rule: AchievementRule,
+}
+
+// Judul dan deskripsi ada di katalog pesan dengan key achievement.<id>.*
+impl AchievementDefinition {
+    pub fn title(&self, locale: Locale) -> String {
+        text(locale, &format!("achievement.{}.title", self.id))
+    }
+
+    pub fn description(&self, locale: Locale) -> String {
+        text(locale, &format!("achievement.{}.description", self.id))
+    }
+}
+
+pub const ACHIEVEMENTS: &[AchievementDefinition] = &[
+    AchievementDefinition {
+        id: "first_check_in",
+        rule: AchievementRule::TotalCheckIns(1),
+    },
+    AchievementDefinition {
+        id: "streak_7",
+        rule: AchievementRule::Streak(7),
+    },
+    AchievementDefinition {
+        id: "streak_30",
+        rule: AchievementRule::Streak(30),
+    },
+    AchievementDefinition {
+        id: "streak_100",
+        rule: AchievementRule::Streak(100),
+    },
+    AchievementDefinition {
+        id: "check_ins_100",
+        rule: AchievementRule::TotalCheckIns(100),
+    },
+    AchievementDefinition {
+        id: "check_ins_1000",
+        rule: AchievementRule::TotalCheckIns(1000),
+    },
+    AchievementDefinition {
+        id: "perfect_week",
+        rule: AchievementRule::PerfectWeek,
+    },
+    AchievementDefinition {
+        id: "perfect_month",
+        rule: AchievementRule::PerfectMonth,
+    },
+    AchievementDefinition {
+        id: "early_bird_week",
+        rule: AchievementRule::EarlyBirdWeek { before_hour: 7 },
+    },
+    AchievementDefinition {
+        id: "all_rounder",
+        rule: AchievementRule::DistinctCategories(5),
+    },
+];
+
+pub struct AchievementEngine;
+
+impl AchievementEngine {
+    pub fn definition(id: &str) -> Option<&'static AchievementDefinition> {
+        ACHIEVEMENTS.iter().find(|a| a.id == id)
+    }
+
+    // Semua achievement yang syaratnya sudah terpenuhi oleh riwayat habit
+    pub fn evaluate(habits: &[Habit], today: NaiveDate) -> Vec<&'static AchievementDefinition> {
+        let mut days: BTreeMap<NaiveDate, Vec<&Habit>> = BTreeMap::new();
+        for habit in habits {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            if date <= today {
+                days.entry(date).or_default().push(habit);
+            }
+        }
+
+        ACHIEVEMENTS
+            .iter()
+            .filter(|definition| Self::is_satisfied(&definition.rule, habits, &days, today))
+            .collect()
+    }
+
+    fn is_satisfied(
+        rule: &AchievementRule,
+        habits: &[Habit],
+        days: &BTreeMap<NaiveDate, Vec<&Habit>>,
+        today: NaiveDate,
+    ) -> bool {
+        match rule {
+            AchievementRule::Streak(length) => Self::longest_streak(days.keys()) >= *length,
+            AchievementRule::TotalCheckIns(count) => habits.len() >= *count,
+            AchievementRule::PerfectWeek => {
+                Self::has_full_week(days.keys().copied().collect(), today)
+            }
+            AchievementRule::PerfectMonth => Self::has_full_month(days, today),
+            AchievementRule::EarlyBirdWeek { before_hour } => {
+                let early_days = days
+                    .iter()
+                    .filter(|(_, entries)| {
+                        entries
+                            .iter()
+                            .any(|h| h.timestamp.with_timezone(&Local).hour() < *before_hour)
+                    })
+                    .map(|(date, _)| *date)
+                    .collect();
+                Self::has_full_week(early_days, today)
+            }
+            AchievementRule::DistinctCategories(count) => {
+                habits
+                    .iter()
+                    .map(|h| h.category.as_str())
+                    .collect::<BTreeSet<_>>()
+                    .len()
+                    >= *count
+            }
+        }
+    }
+
+    fn longest_streak<'a>(dates: impl Iterator<Item = &'a NaiveDate>) -> usize {
+        let mut longest = 0;
+        let mut current = 0;
+        let mut previous: Option<NaiveDate> = None;
+
+        for date in dates {
+            current = match previous {
+                Some(prev) if *date - prev == Duration::days(1) => current + 1,
+                _ => 1,
+            };
+            longest = longest.max(current);
+            previous = Some(*date);
+        }
+        longest
+    }
+
+    // Minggu harus sudah selesai (Minggu <= hari ini) dan lengkap tujuh hari
+    fn has_full_week(dates: BTreeSet<NaiveDate>, today: NaiveDate) -> bool {
+        dates.iter().any(|date| {
+            date.weekday().num_days_from_monday() == 0
+                && *date + Duration::days(6) <= today
+                && (1..7).all(|i| dates.contains(&(*date + Duration::days(i))))
+        })
+    }
+
+    fn has_full_month(days: &BTreeMap<NaiveDate, Vec<&Habit>>, today: NaiveDate) -> bool {
+        days.keys().filter(|date| date.day() == 1).any(|first| {
+            let mut date = *first;
+            while date.month() == first.month() {
+                if date > today || !days.contains_key(&date) {
+                    return false;
+                }
+                date += Duration::days(1);
+            }
+            true
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use chrono::{Datelike, Duration, Local, NaiveDate, Utc};
+use std::collections::BTreeMap;
+
+use crate::i18n::Locale;
+use crate::models::{
+    AnomalyDirection, AnomalyEvent, AnomalyGranularity, Habit, Insight, InsightType, Severity,
+};
+use crate::statistics::{describe_insight, StatisticsCalculator};
+
+const BASELINE_DAYS: usize = 28;
+const BASELINE_WEEKS: usize = 8;
+// Baseline harus punya cukup hari/minggu aktif, kalau tidak setiap entry jadi "spike"
+const MIN_ACTIVE_DAYS: usize = 7;
+const MIN_ACTIVE_WEEKS: usize = 4;
+const Z_THRESHOLD: f64 = 2.5;
+// Skala minimum (setengah entry) supaya MAD = 0 tidak membuat z tak hingga
+const MIN_SCALE: f64 = 0.5;
+
+type SubjectKey = (Option<String>, Option<String>);
+
+impl StatisticsCalculator {
+    pub fn detect_anomalies(
+        habits: &[Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+        today: NaiveDate,
+    ) -> Vec<AnomalyEvent> {
+        // Hari ini belum selesai dan hari setelahnya belum terjadi, jadi bukan penurunan
+        let end = end.min(today - Duration::days(1));
+        if end < start {
+            return Vec::new();
+        }
+        let history_start = start - Duration::days((BASELINE_WEEKS * 7) as i64);
+        let days = ((end - history_start).num_days() + 1).max(0) as usize;
+
+        let mut series: BTreeMap<SubjectKey, Vec<f64>> = BTreeMap::new();
+        series.insert((None, None), vec![0.0; days]);
+        for habit in habits {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            if date < history_start || date > end {
+                continue;
+            }
+            let offset = (date - history_start).num_days() as usize;
+            let keys = [
+                (None, None),
+                (Some(habit.name.clone()), None),
+                (None, Some(habit.category.as_str().to_string())),
+            ];
+            for key in keys {
+                series.entry(key).or_insert_with(|| vec![0.0; days])[offset] += 1.0;
+            }
+        }
+
+        let first_index = (start - history_start).num_days() as usize;
+        let mut events = Vec::new();
+
+        for ((habit_name, category), values) in &series {
+            for index in first_index..values.len() {
+                let baseline = &values[index.saturating_sub(BASELINE_DAYS)..index];
+                let date = history_start + Duration::days(index as i64);
+                if let Some((median, z)) = Self::robust_z(values[index], baseline, MIN_ACTIVE_DAYS)
+                {
+                    events.push(Self::anomaly_event(
+                        habit_name,
+                        category,
+                        AnomalyGranularity::Day,
+                        date,
+                        date,
+                        values[index],
+                        median,
+                        z,
+                    ));
+                }
+            }
+
+            // Minggu dimulai hari Senin, sama seperti calculate_monthly_stats
+            let weekly = Self::weekly_totals(values, history_start);
+            for (index, (week_start, total)) in weekly.iter().enumerate() {
+                let week_end = *week_start + Duration::days(6);
+                if *week_start < start || week_end > end {
+                    continue;
+                }
+                let baseline: Vec<f64> = weekly[index.saturating_sub(BASELINE_WEEKS)..index]
+                    .iter()
+                    .map(|(_, t)| *t)
+                    .collect();
+                if let Some((median, z)) = Self::robust_z(*total, &baseline, MIN_ACTIVE_WEEKS) {
+                    events.push(Self::anomaly_event(
+                        habit_name,
+                        category,
+                        AnomalyGranularity::Week,
+                        *week_start,
+                        week_end,
+                        *total,
+                        median,
+                        z,
+                    ));
+                }
+            }
+        }
+
+        events.sort_by_key(|e| e.timestamp);
+        events
+    }
+
+    pub fn anomaly_insights(events: &[AnomalyEvent], limit: usize, locale: Locale) -> Vec<Insight> {
+        let mut dips: Vec<&AnomalyEvent> = events
+            .iter()
+            .filter(|e| e.direction == AnomalyDirection::Dip && e.severity >= Severity::Medium)
+            .collect();
+        dips.sort_by(|a, b| {
+            a.robust_z
+                .partial_cmp(&b.robust_z)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        dips.into_iter()
+            .take(limit)
+            .map(|event| {
+                let period_start =
+                    NaiveDate::parse_from_str(&event.period_start, "%Y-%m-%d").unwrap_or_default();
+                let period_end =
+                    NaiveDate::parse_from_str(&event.period_end, "%Y-%m-%d").unwrap_or_default();
+                let granularity = match event.granularity {
+                    AnomalyGranularity::Day => "day",
+                    AnomalyGranularity::Week => "week",
+                };
+                // Id tidak boleh ikut berubah saat locale diganti, jadi subject-nya bukan teks
+                let subject = match (&event.habit_name, &event.category) {
+                    (Some(name), _) => name.clone(),
+                    (None, Some(category)) => format!("category={}", category),
+                    (None, None) => "all".to_string(),
+                };
+
+                let mut insight = Insight::new(
+                    format!("anomaly:{}:{}:{}", subject, granularity, event.period_start),
+                    "anomaly",
+                    InsightType::Warning,
+                    event.severity,
+                    period_start,
+                    period_end,
+                );
+                insight.habit_names.extend(event.habit_name.clone());
+                insight.categories.extend(event.category.clone());
+                insight
+                    .metrics
+                    .insert("observed".to_string(), event.observed);
+                insight
+                    .metrics
+                    .insert("baseline_median".to_string(), event.baseline_median);
+                insight
+                    .metrics
+                    .insert("robust_z".to_string(), event.robust_z);
+                insight.message = describe_insight(&insight, locale);
+                insight
+            })
+            .collect()
+    }
+
+    // Mengembalikan (median baseline, robust z-score) kalau nilainya menyimpang signifikan
+    fn robust_z(value: f64, baseline: &[f64], min_active: usize) -> Option<(f64, f64)> {
+        if baseline.iter().filter(|v| **v > 0.0).count() < min_active {
+            return None;
+        }
+
+        let median = Self::median(baseline);
+        let deviations: Vec<f64> = baseline.iter().map(|v| (v - median).abs()).collect();
+        let mad = Self::median(&deviations);
+
+        // 1.4826 * MAD ~ standar deviasi untuk data normal
+        let scale = (1.4826 * mad).max(MIN_SCALE);
+        let z = (value - median) / scale;
+
+        if z.abs() >= Z_THRESHOLD {
+            Some((median, z))
+        } else {
+            None
+        }
+    }
+
+    fn median(values: &[f64]) -> f64 {
+        if values.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let mid = sorted.len() / 2;
+        if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        }
+    }
+
+    fn weekly_totals(values: &[f64], history_start: NaiveDate) -> Vec<(NaiveDate, f64)> {
+        // Hanya minggu penuh, minggu parsial di awal riwayat akan merusak baseline
+        let mut offset = (7 - history_start.weekday().num_days_from_monday() as usize) % 7;
+
+        let mut totals = Vec::new();
+        while offset + 7 <= values.len() {
+            let week_start = history_start + Duration::days(offset as i64);
+            totals.push((week_start, values[offset..offset + 7].iter().sum()));
+            offset += 7;
+        }
+        totals
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn anomaly_event(
+        habit_name: &Option<String>,
+        category: &Option<String>,
+        granularity: AnomalyGranularity,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        observed: f64,
+        baseline_median: f64,
+        robust_z: f64,
+    ) -> AnomalyEvent {
+        let severity = match robust_z.abs() {
+            z if z >= 4.0 => Severity::High,
+            z if z >= 3.0 => Severity::Medium,
+            _ => Severity::Low,
+        };
+
+        let timestamp = period_start
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+
+        AnomalyEvent {
+            habit_name: habit_name.clone(),
+            category: category.clone(),
+            granularity,
+            timestamp,
+            period_start: period_start.to_string(),
+            period_end: period_end.to_string(),
+            observed,
+            baseline_median,
+            robust_z,
+            direction: if robust_z < 0.0 {
+                AnomalyDirection::Dip
+            } else {
+                AnomalyDirection::Spike
+            },
+            severity,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use chrono::{Duration, Local, NaiveDate};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use crate::i18n::Locale;
+use crate::models::{
+    BalanceRadar, BalanceReport, BalanceTarget, Category, CategoryBalance, DailyStats, Habit,
+    Insight, InsightType, Severity,
+};
+use crate::statistics::{describe_insight, StatisticsCalculator};
+
+pub const DEFAULT_NEGLECT_DAYS: i64 = 7;
+
+impl StatisticsCalculator {
+    // Bobot efektif per kategori; "other" bukan area hidup jadi default-nya 0
+    pub fn balance_targets(weights: &HashMap<String, f64>) -> Vec<BalanceTarget> {
+        Category::ALL
+            .iter()
+            .map(|category| BalanceTarget {
+                category: category.as_str().to_string(),
+                weight: weights.get(category.as_str()).copied().unwrap_or(
+                    if *category == Category::Other {
+                        0.0
+                    } else {
+                        1.0
+                    },
+                ),
+            })
+            .collect()
+    }
+
+    // Area yang diperhatikan untuk peringatan "terabaikan": bobot target lebih dari nol
+    pub fn watched_categories(weights: &HashMap<String, f64>) -> Vec<Category> {
+        Self::balance_targets(weights)
+            .into_iter()
+            .filter(|target| target.weight > 0.0)
+            .map(|target| Category::from_str(&target.category))
+            .collect()
+    }
+
+    pub fn calculate_balance_report(
+        habits: &[Habit],
+        weights: &HashMap<String, f64>,
+        start: NaiveDate,
+        end: NaiveDate,
+        today: NaiveDate,
+        neglect_days: i64,
+        locale: Locale,
+    ) -> BalanceReport {
+        let days = ((end - start).num_days() + 1).max(0);
+        let daily: Vec<DailyStats> = (0..days)
+            .into_par_iter()
+            .map(|i| Self::calculate_daily_stats(habits, start + Duration::days(i)))
+            .collect();
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for day in &daily {
+            for category in &day.by_category {
+                *counts.entry(category.category.clone()).or_insert(0) += category.count;
+            }
+        }
+        let total: usize = counts.values().sum();
+
+        let targets = Self::balance_targets(weights);
+        let weight_sum: f64 = targets.iter().map(|t| t.weight.max(0.0)).sum();
+
+        let reference = today.min(end);
+        let last_dates = Self::last_entry_dates(habits, reference);
+
+        let categories: Vec<CategoryBalance> = targets
+            .iter()
+            .map(|target| {
+                let count = counts.get(&target.category).copied().unwrap_or(0);
+                let actual_share = if total > 0 {
+                    count as f64 / total as f64
+                } else {
+                    0.0
+                };
+                let target_share = if weight_sum > 0.0 {
+                    target.weight.max(0.0) / weight_sum
+                } else {
+                    0.0
+                };
+                let days_since_last = last_dates
+                    .get(&target.category)
+                    .map(|date| (reference - *date).num_days());
+
+                CategoryBalance {
+                    category: target.category.clone(),
+                    count,
+                    actual_share,
+                    target_share,
+                    deviation: actual_share - target_share,
+                    days_since_last,
+                    neglected: target_share > 0.0
+                        && days_since_last.is_none_or(|d| d >= neglect_days),
+                }
+            })
+            .collect();
+
+        // 1 - total variation distance antara porsi aktual dan target
+        let score = if total > 0 && weight_sum > 0.0 {
+            let distance: f64 = categories.iter().map(|c| c.deviation.abs()).sum::<f64>() / 2.0;
+            ((1.0 - distance) * 100.0).clamp(0.0, 100.0)
+        } else {
+            0.0
+        };
+
+        let charted: Vec<&CategoryBalance> = categories
+            .iter()
+            .filter(|c| c.target_share > 0.0 || c.count > 0)
+            .collect();
+        let radar = BalanceRadar {
+            labels: charted.iter().map(|c| c.category.clone()).collect(),
+            actual: charted.iter().map(|c| c.actual_share * 100.0).collect(),
+            target: charted.iter().map(|c| c.target_share * 100.0).collect(),
+        };
+
+        let watched = Self::watched_categories(weights);
+        let insights = Self::neglect_insights(habits, &watched, reference, neglect_days, locale);
+
+        BalanceReport {
+            start: start.to_string(),
+            end: end.to_string(),
+            total,
+            score,
+            neglect_days,
+            categories,
+            radar,
+            insights,
+        }
+    }
+
+    pub fn neglect_insights(
+        habits: &[Habit],
+        categories: &[Category],
+        reference: NaiveDate,
+        neglect_days: i64,
+        locale: Locale,
+    ) -> Vec<Insight> {
+        let last_dates = Self::last_entry_dates(habits, reference);
+
+        categories
+            .iter()
+            .filter_map(|category| {
+                // Syaratnya sama dengan CategoryBalance.neglected, termasuk area yang belum
+                // pernah dicatat sama sekali
+                let last = last_dates.get(category.as_str()).copied();
+                let days_since = last.map(|last| (reference - last).num_days());
+                if days_since.is_some_and(|d| d < neglect_days) {
+                    return None;
+                }
+
+                let severity = match days_since {
+                    Some(d) if d < neglect_days * 2 => Severity::Medium,
+                    _ => Severity::High,
+                };
+                let (id_suffix, period_start) = match last {
+                    Some(last) => (last.to_string(), last + Duration::days(1)),
+                    None => ("never".to_string(), reference),
+                };
+                let mut insight = Insight::new(
+                    format!("neglected_area:{}:{}", category.as_str(), id_suffix),
+                    "neglected_area",
+                    InsightType::Warning,
+                    severity,
+                    period_start,
+                    reference,
+                );
+                if let Some(days_since) = days_since {
+                    insight
+                        .metrics
+                        .insert("days_since_last".to_string(), days_since as f64);
+                }
+                insight
+                    .metrics
+                    .insert("neglect_days".to_string(), neglect_days as f64);
+                insight.categories.push(category.as_str().to_string());
+                insight.message = describe_insight(&insight, locale);
+                Some(insight)
+            })
+            .collect()
+    }
+
+    fn last_entry_dates(habits: &[Habit], reference: NaiveDate) -> HashMap<String, NaiveDate> {
+        let mut last_dates: HashMap<String, NaiveDate> = HashMap::new();
+        for habit in habits {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            if date > reference {
+                continue;
+            }
+            let last = last_dates
+                .entry(habit.category.as_str().to_string())
+                .or_insert(date);
+            if date > *last {
+                *last = date;
+            }
+        }
+        last_dates
+    }
+}
@@ -1,10 +1,12 @@
-use chrono::{Datelike, Duration, Local, NaiveDate, Timelike, Utc, Weekday};
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
 use rayon::prelude::*;
 use std::collections::HashMap;
 
+use crate::i18n::{month_name, Locale};
 use crate::models::{
-    CategoryCount, DailyStats, Habit, Insight, InsightType, MonthlyStats, Trend, WeeklyStats,
+    CategoryCount, DailyStats, Habit, InsightPreferences, MonthlyStats, WeeklyStats,
 };
+use crate::statistics::{InsightContext, InsightEngine, TrendAnalyzer};
 
 pub struct StatisticsCalculator;
 
@@ -72,7 +74,8 @@ impl StatisticsCalculator {
             .map(|d| d.date.clone())
             .unwrap_or_default();
 
-        let trend = Self::calculate_trend(&days);
+        let counts: Vec<Option<f64>> = days.iter().map(|d| Some(d.total_habits as f64)).collect();
+        let trend_analysis = TrendAnalyzer::analyze(&counts);
 
         WeeklyStats {
             week_start: week_start.to_string(),
@@ -80,11 +83,19 @@ impl StatisticsCalculator {
             days,
             total_habits,
             most_active_day,
-            trend,
+            trend: trend_analysis.direction.clone(),
+            trend_analysis,
         }
     }
 
-    pub fn calculate_monthly_stats(habits: &[Habit], year: i32, month: u32) -> MonthlyStats {
+    pub fn calculate_monthly_stats(
+        habits: &[Habit],
+        year: i32,
+        month: u32,
+        preferences: &InsightPreferences,
+        today: NaiveDate,
+        locale: Locale,
+    ) -> MonthlyStats {
         let first_day = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
         let last_day = if month == 12 {
             NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap() - Duration::days(1)
@@ -136,168 +147,20 @@ impl StatisticsCalculator {
             .map(|(cat, _)| cat)
             .unwrap_or_else(|| "none".to_string());
 
-        let days_in_month = (last_day - first_day).num_days() + 1;
-        let active_days = habits
-            .iter()
-            .filter(|h| {
-                let date = h.timestamp.with_timezone(&Local).date_naive();
-                date >= first_day && date <= last_day
-            })
-            .map(|h| h.timestamp.with_timezone(&Local).date_naive())
-            .collect::<std::collections::HashSet<_>>()
-            .len();
-
-        let consistency_percentage = (active_days as f64 / days_in_month as f64) * 100.0;
-
-        let insights = Self::generate_insights(&month_habits, consistency_percentage);
-
-        let month_name = match month {
-            1 => "Januari",
-            2 => "Februari",
-            3 => "Maret",
-            4 => "April",
-            5 => "Mei",
-            6 => "Juni",
-            7 => "Juli",
-            8 => "Agustus",
-            9 => "September",
-            10 => "Oktober",
-            11 => "November",
-            12 => "Desember",
-            _ => "Unknown",
-        };
+        let ctx = InsightContext::new(habits, first_day, last_day, today, locale)
+            .with_balance_weights(preferences.balance_weights.clone());
+        let consistency_percentage = ctx.consistency();
+        let insights = InsightEngine::default().generate(&ctx, preferences);
 
         MonthlyStats {
-            month: month_name.to_string(),
+            month: month_name(locale, month),
             year,
             weeks,
             total_habits,
             consistency_percentage,
             most_common_category,
-            insights: insights.iter().map(|i| i.message.clone()).collect(),
-        }
-    }
-
-    // MODIFIED: Improved trend calculation dengan threshold yang lebih baik
-    fn calculate_trend(days: &[DailyStats]) -> Trend {
-        if days.len() < 2 {
-            return Trend::Stable;
-        }
-
-        let total: usize = days.iter().map(|d| d.total_habits).sum();
-        
-        // Butuh minimal 5 habit untuk calculate trend
-        if total < 5 {
-            return Trend::Stable;
-        }
-
-        let days_with_data: Vec<&DailyStats> = days.iter()
-            .filter(|d| d.total_habits > 0)
-            .collect();
-        
-        // Butuh minimal 3 hari dengan data
-        if days_with_data.len() < 3 {
-            return Trend::Stable;
+            insights,
         }
-
-        let mid = days.len() / 2;
-        let first_half: usize = days[..mid].iter().map(|d| d.total_habits).sum();
-        let second_half: usize = days[mid..].iter().map(|d| d.total_habits).sum();
-
-        let first_avg = first_half as f64 / mid as f64;
-        let second_avg = second_half as f64 / (days.len() - mid) as f64;
-        
-        let diff = second_avg - first_avg;
-        let threshold = 0.8;
-
-        if diff > threshold {
-            Trend::Up
-        } else if diff < -threshold {
-            Trend::Down
-        } else {
-            Trend::Stable
-        }
-    }
-
-    fn generate_insights(habits: &[&Habit], consistency: f64) -> Vec<Insight> {
-        let mut insights = Vec::new();
-
-        if consistency >= 80.0 {
-            insights.push(Insight {
-                message: format!(
-                    "Konsistensi sangat baik! Kamu aktif {:.1}% dari hari dalam bulan ini.",
-                    consistency
-                ),
-                insight_type: InsightType::Achievement,
-            });
-        } else if consistency >= 50.0 {
-            insights.push(Insight {
-                message: format!(
-                    "Konsistensi cukup baik ({:.1}%). Terus tingkatkan!",
-                    consistency
-                ),
-                insight_type: InsightType::Pattern,
-            });
-        } else {
-            insights.push(Insight {
-                message: format!(
-                    "Konsistensi masih rendah ({:.1}%). Coba buat pengingat harian.",
-                    consistency
-                ),
-                insight_type: InsightType::Suggestion,
-            });
-        }
-
-        // MODIFIED: Fixed timezone untuk hour calculation
-        let mut hour_counts: HashMap<u32, usize> = HashMap::new();
-        for habit in habits {
-            let hour = habit.timestamp.with_timezone(&Local).hour();
-            *hour_counts.entry(hour).or_insert(0) += 1;
-        }
-
-        if let Some((most_active_hour, _)) = hour_counts.into_iter().max_by_key(|(_, count)| *count)
-        {
-            let time_period = match most_active_hour {
-                5..=11 => "pagi",
-                12..=17 => "siang/sore",
-                18..=21 => "malam",
-                _ => "larut malam",
-            };
-            insights.push(Insight {
-                message: format!(
-                    "Kamu paling aktif di waktu {} (sekitar jam {}).",
-                    time_period, most_active_hour
-                ),
-                insight_type: InsightType::Pattern,
-            });
-        }
-
-        // MODIFIED: Fixed timezone untuk weekday calculation
-        let mut weekday_counts: HashMap<Weekday, usize> = HashMap::new();
-        for habit in habits {
-            let weekday = habit.timestamp.with_timezone(&Local).weekday();
-            *weekday_counts.entry(weekday).or_insert(0) += 1;
-        }
-
-        if let Some((most_active_weekday, _)) =
-            weekday_counts.into_iter().max_by_key(|(_, count)| *count)
-        {
-            let day_name = match most_active_weekday {
-                Weekday::Mon => "Senin",
-                Weekday::Tue => "Selasa",
-                Weekday::Wed => "Rabu",
-                Weekday::Thu => "Kamis",
-                Weekday::Fri => "Jumat",
-                Weekday::Sat => "Sabtu",
-                Weekday::Sun => "Minggu",
-            };
-            insights.push(Insight {
-                message: format!("Hari paling aktif adalah {}.", day_name),
-                insight_type: InsightType::Pattern,
-            });
-        }
-
-        insights
     }
 
     // MODIFIED: Streak calculation dari tanggal paling baru, bukan dari hari ini
@@ -0,0 +1,148 @@
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::{
+    ComplianceBreakdown, ComplianceBucket, ComplianceHistogram, ComplianceReport, Habit,
+    RatingScale,
+};
+use crate::statistics::{StatisticsCalculator, TrendAnalyzer};
+
+impl StatisticsCalculator {
+    pub fn calculate_compliance_report(
+        habits: &[Habit],
+        scales: &HashMap<String, RatingScale>,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> ComplianceReport {
+        let rated: Vec<(&Habit, u8)> = habits
+            .iter()
+            .filter(|h| {
+                let date = h.timestamp.with_timezone(&Local).date_naive();
+                date >= start && date <= end
+            })
+            .filter_map(|h| h.compliance_level.map(|level| (h, level)))
+            .collect();
+
+        let scale_of = |name: &str| scales.get(name).cloned().unwrap_or_default();
+
+        ComplianceReport {
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            histograms: Self::compliance_histograms(&rated, &scale_of),
+            trend: TrendAnalyzer::analyze(&Self::compliance_series(&rated, &scale_of, start, end)),
+            by_category: Self::compliance_breakdown(&rated, &scale_of, |h| {
+                h.category.as_str().to_string()
+            }),
+            by_weekday: Self::compliance_by_weekday(&rated, &scale_of),
+        }
+    }
+
+    fn compliance_histograms(
+        rated: &[(&Habit, u8)],
+        scale_of: &impl Fn(&str) -> RatingScale,
+    ) -> Vec<ComplianceHistogram> {
+        let mut by_habit: BTreeMap<&str, Vec<u8>> = BTreeMap::new();
+        for (habit, level) in rated {
+            by_habit
+                .entry(habit.name.as_str())
+                .or_default()
+                .push(*level);
+        }
+
+        by_habit
+            .into_iter()
+            .map(|(name, levels)| {
+                let scale = scale_of(name);
+                let (min, max) = scale.bounds();
+
+                let buckets = (min..=max)
+                    .map(|value| ComplianceBucket {
+                        value,
+                        label: scale.label(value),
+                        count: levels.iter().filter(|l| **l == value).count(),
+                    })
+                    .collect();
+
+                let average = levels.iter().map(|l| *l as f64).sum::<f64>() / levels.len() as f64;
+
+                ComplianceHistogram {
+                    habit_name: name.to_string(),
+                    scale,
+                    buckets,
+                    total: levels.len(),
+                    average,
+                }
+            })
+            .collect()
+    }
+
+    // Rata-rata harian yang sudah dinormalisasi, untuk dianalisis TrendAnalyzer
+    fn compliance_series(
+        rated: &[(&Habit, u8)],
+        scale_of: &impl Fn(&str) -> RatingScale,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Vec<Option<f64>> {
+        let mut by_date: HashMap<NaiveDate, Vec<f64>> = HashMap::new();
+        for (habit, level) in rated {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            by_date
+                .entry(date)
+                .or_default()
+                .push(scale_of(&habit.name).normalize(*level));
+        }
+
+        let mut series = Vec::new();
+        let mut date = start;
+        while date <= end {
+            series.push(
+                by_date
+                    .get(&date)
+                    .map(|values| values.iter().sum::<f64>() / values.len() as f64),
+            );
+            date += Duration::days(1);
+        }
+        series
+    }
+
+    fn compliance_breakdown(
+        rated: &[(&Habit, u8)],
+        scale_of: &impl Fn(&str) -> RatingScale,
+        key_of: impl Fn(&Habit) -> String,
+    ) -> Vec<ComplianceBreakdown> {
+        let mut groups: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+        for (habit, level) in rated {
+            groups
+                .entry(key_of(habit))
+                .or_default()
+                .push(scale_of(&habit.name).normalize(*level));
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, values)| ComplianceBreakdown {
+                key,
+                count: values.len(),
+                average_percentage: values.iter().sum::<f64>() / values.len() as f64 * 100.0,
+            })
+            .collect()
+    }
+
+    fn compliance_by_weekday(
+        rated: &[(&Habit, u8)],
+        scale_of: &impl Fn(&str) -> RatingScale,
+    ) -> Vec<ComplianceBreakdown> {
+        let mut breakdown = Self::compliance_breakdown(rated, scale_of, |h| {
+            h.timestamp.with_timezone(&Local).weekday().to_string()
+        });
+
+        // Urutkan Senin-Minggu, bukan alfabetis
+        let order = |key: &str| {
+            key.parse::<Weekday>()
+                .map(|w| w.num_days_from_monday())
+                .unwrap_or(7)
+        };
+        breakdown.sort_by_key(|b| order(&b.key));
+        breakdown
+    }
+}
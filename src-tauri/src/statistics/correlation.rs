@@ -0,0 +1,238 @@
+use chrono::{Duration, Local, NaiveDate};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::{
+    CorrelationFinding, CorrelationReport, CorrelationSubject, Habit, SubjectKind,
+};
+
+pub const DEFAULT_MIN_SAMPLES: usize = 14;
+pub const DEFAULT_FINDING_LIMIT: usize = 10;
+// Setiap subject punya satu nilai per hari, jadi rentangnya dibatasi
+pub const MAX_CORRELATION_DAYS: i64 = 730;
+
+// Minimal berapa hari subject harus muncul (dan tidak muncul) supaya koefisien bermakna
+const MIN_OCCURRENCES: usize = 3;
+
+pub struct CorrelationAnalyzer;
+
+impl CorrelationAnalyzer {
+    pub fn analyze(
+        habits: &[Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+        min_samples: usize,
+        limit: usize,
+    ) -> CorrelationReport {
+        let sample_days = if end >= start {
+            (end - start).num_days() as usize + 1
+        } else {
+            0
+        };
+
+        let series = Self::build_daily_series(habits, start, sample_days);
+        let subjects: Vec<&CorrelationSubject> = series.keys().collect();
+        let habit_categories = Self::habit_categories(habits);
+
+        let mut pairs = Vec::new();
+        for a in &subjects {
+            for b in &subjects {
+                if a == b || Self::is_nested(a, b, &habit_categories) {
+                    continue;
+                }
+                // Hari yang sama simetris, cukup dihitung sekali per pasangan
+                if a < b {
+                    pairs.push((*a, *b, 0u32));
+                }
+                pairs.push((*a, *b, 1u32));
+            }
+        }
+
+        let results: Vec<Option<CorrelationFinding>> = pairs
+            .par_iter()
+            .map(|(a, b, lag)| {
+                Self::compare(a, &series[*a], b, &series[*b], *lag as usize, min_samples)
+            })
+            .collect();
+
+        let pairs_tested = results.len();
+        let mut findings: Vec<CorrelationFinding> = results.into_iter().flatten().collect();
+        let pairs_skipped = pairs_tested - findings.len();
+
+        findings.sort_by(|x, y| {
+            y.phi
+                .abs()
+                .partial_cmp(&x.phi.abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(y.sample_days.cmp(&x.sample_days))
+        });
+        findings.truncate(limit);
+
+        CorrelationReport {
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            sample_days,
+            min_samples,
+            pairs_tested,
+            pairs_skipped,
+            findings,
+        }
+    }
+
+    fn build_daily_series(
+        habits: &[Habit],
+        start: NaiveDate,
+        days: usize,
+    ) -> BTreeMap<CorrelationSubject, Vec<f64>> {
+        let mut series: BTreeMap<CorrelationSubject, Vec<f64>> = BTreeMap::new();
+
+        for habit in habits {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            let offset = (date - start).num_days();
+            if offset < 0 || offset as usize >= days {
+                continue;
+            }
+
+            let keys = [
+                CorrelationSubject {
+                    kind: SubjectKind::Habit,
+                    name: habit.name.clone(),
+                },
+                CorrelationSubject {
+                    kind: SubjectKind::Category,
+                    name: habit.category.as_str().to_string(),
+                },
+            ];
+            for key in keys {
+                series.entry(key).or_insert_with(|| vec![0.0; days])[offset as usize] += 1.0;
+            }
+        }
+
+        series
+    }
+
+    fn habit_categories(habits: &[Habit]) -> HashMap<&str, Vec<&'static str>> {
+        let mut map: HashMap<&str, Vec<&'static str>> = HashMap::new();
+        for habit in habits {
+            let categories = map.entry(habit.name.as_str()).or_default();
+            let category = habit.category.as_str();
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+        map
+    }
+
+    // Habit vs kategorinya sendiri pasti berkorelasi, jadi tidak ikut diuji
+    fn is_nested(
+        a: &CorrelationSubject,
+        b: &CorrelationSubject,
+        habit_categories: &HashMap<&str, Vec<&'static str>>,
+    ) -> bool {
+        let (habit, category) = match (&a.kind, &b.kind) {
+            (SubjectKind::Habit, SubjectKind::Category) => (a, b),
+            (SubjectKind::Category, SubjectKind::Habit) => (b, a),
+            _ => return false,
+        };
+
+        habit_categories
+            .get(habit.name.as_str())
+            .map(|cats| cats.contains(&category.name.as_str()))
+            .unwrap_or(false)
+    }
+
+    fn compare(
+        a: &CorrelationSubject,
+        a_series: &[f64],
+        b: &CorrelationSubject,
+        b_series: &[f64],
+        lag: usize,
+        min_samples: usize,
+    ) -> Option<CorrelationFinding> {
+        if a_series.len() <= lag {
+            return None;
+        }
+
+        let xs = &a_series[..a_series.len() - lag];
+        let ys = &b_series[lag..];
+        let n = xs.len();
+
+        let mut both = 0;
+        let mut only_a = 0;
+        let mut only_b = 0;
+        for (x, y) in xs.iter().zip(ys) {
+            match (*x > 0.0, *y > 0.0) {
+                (true, true) => both += 1,
+                (true, false) => only_a += 1,
+                (false, true) => only_b += 1,
+                (false, false) => {}
+            }
+        }
+        let neither = n - both - only_a - only_b;
+
+        let a_days = both + only_a;
+        let b_days = both + only_b;
+
+        if n < min_samples
+            || a_days < MIN_OCCURRENCES
+            || n - a_days < MIN_OCCURRENCES
+            || b_days < MIN_OCCURRENCES
+            || n - b_days < MIN_OCCURRENCES
+        {
+            return None;
+        }
+
+        let phi = Self::phi(both, only_a, only_b, neither)?;
+
+        Some(CorrelationFinding {
+            subject_a: a.clone(),
+            subject_b: b.clone(),
+            lag_days: lag as u32,
+            sample_days: n,
+            a_days,
+            b_days,
+            both_days: both,
+            rate_with_a: both as f64 / a_days as f64,
+            rate_without_a: only_b as f64 / (n - a_days) as f64,
+            phi,
+            pearson: Self::pearson(xs, ys),
+        })
+    }
+
+    pub fn phi(both: usize, only_a: usize, only_b: usize, neither: usize) -> Option<f64> {
+        let (n11, n10, n01, n00) = (both as f64, only_a as f64, only_b as f64, neither as f64);
+        let denominator = ((n11 + n10) * (n01 + n00) * (n11 + n01) * (n10 + n00)).sqrt();
+        if denominator == 0.0 {
+            return None;
+        }
+        Some((n11 * n00 - n10 * n01) / denominator)
+    }
+
+    pub fn pearson(xs: &[f64], ys: &[f64]) -> Option<f64> {
+        let n = xs.len().min(ys.len());
+        if n < 2 {
+            return None;
+        }
+
+        let mean_x = xs[..n].iter().sum::<f64>() / n as f64;
+        let mean_y = ys[..n].iter().sum::<f64>() / n as f64;
+
+        let mut covariance = 0.0;
+        let mut var_x = 0.0;
+        let mut var_y = 0.0;
+        for (x, y) in xs.iter().zip(ys).take(n) {
+            covariance += (x - mean_x) * (y - mean_y);
+            var_x += (x - mean_x).powi(2);
+            var_y += (y - mean_y).powi(2);
+        }
+
+        if var_x == 0.0 || var_y == 0.0 {
+            return None;
+        }
+        Some(covariance / (var_x.sqrt() * var_y.sqrt()))
+    }
+
+    pub fn default_range(today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        (today - Duration::days(89), today)
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::{Duration, Local, NaiveDate};
+use std::collections::BTreeMap;
+
+use crate::models::{DurationBucket, DurationReport, DurationTotal, Habit, TimeGrouping};
+use crate::statistics::StatisticsCalculator;
+
+#[derive(Default)]
+struct DurationAccumulator {
+    minutes: f64,
+    sessions: usize,
+}
+
+impl StatisticsCalculator {
+    // Hanya entry dengan end_timestamp (sesi timer, tidur) yang dihitung
+    pub fn calculate_duration_report(
+        habits: &[Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+        period: TimeGrouping,
+    ) -> DurationReport {
+        let timed: Vec<(NaiveDate, &Habit, f64)> = habits
+            .iter()
+            .filter_map(|h| {
+                let date = h.timestamp.with_timezone(&Local).date_naive();
+                let minutes = h.duration_minutes()?;
+                (date >= start && date <= end).then_some((date, h, minutes))
+            })
+            .collect();
+
+        let mut buckets = Vec::new();
+        let mut bucket_start = period.period_start(start);
+        while bucket_start <= end {
+            let next = period.next_period_start(bucket_start);
+            let entries: Vec<&(NaiveDate, &Habit, f64)> = timed
+                .iter()
+                .filter(|(date, _, _)| *date >= bucket_start && *date < next)
+                .collect();
+
+            buckets.push(DurationBucket {
+                period_start: bucket_start.to_string(),
+                period_end: (next - Duration::days(1)).to_string(),
+                total_minutes: entries.iter().map(|(_, _, minutes)| minutes).sum(),
+                by_habit: Self::duration_totals(
+                    entries.iter().map(|(_, h, m)| (h.name.clone(), *m)),
+                ),
+                by_category: Self::duration_totals(
+                    entries
+                        .iter()
+                        .map(|(_, h, m)| (h.category.as_str().to_string(), *m)),
+                ),
+            });
+            bucket_start = next;
+        }
+
+        DurationReport {
+            period,
+            start: start.to_string(),
+            end: end.to_string(),
+            buckets,
+            by_habit: Self::duration_totals(timed.iter().map(|(_, h, m)| (h.name.clone(), *m))),
+            by_category: Self::duration_totals(
+                timed
+                    .iter()
+                    .map(|(_, h, m)| (h.category.as_str().to_string(), *m)),
+            ),
+        }
+    }
+
+    fn duration_totals(entries: impl Iterator<Item = (String, f64)>) -> Vec<DurationTotal> {
+        let mut totals: BTreeMap<String, DurationAccumulator> = BTreeMap::new();
+        for (key, minutes) in entries {
+            let total = totals.entry(key).or_default();
+            total.minutes += minutes;
+            total.sessions += 1;
+        }
+
+        let mut result: Vec<DurationTotal> = totals
+            .into_iter()
+            .map(|(key, total)| DurationTotal {
+                key,
+                total_minutes: total.minutes,
+                sessions: total.sessions,
+                average_minutes: total.minutes / total.sessions as f64,
+            })
+            .collect();
+        result.sort_by(|a, b| {
+            b.total_minutes
+                .partial_cmp(&a.total_minutes)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        result
+    }
+}
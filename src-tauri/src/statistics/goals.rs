@@ -0,0 +1,144 @@
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use std::collections::HashSet;
+
+use crate::models::{
+    Goal, GoalHistory, GoalMetric, GoalPeriod, GoalPeriodResult, GoalProgress, Habit,
+};
+
+pub struct GoalTracker;
+
+impl GoalTracker {
+    pub fn period_bounds(goal: &Goal, date: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        match goal.period {
+            GoalPeriod::Week => {
+                let start = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+                Some((start, start + Duration::days(6)))
+            }
+            GoalPeriod::Month => {
+                let start = NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?;
+                let next = if date.month() == 12 {
+                    NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)?
+                } else {
+                    NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)?
+                };
+                Some((start, next - Duration::days(1)))
+            }
+            GoalPeriod::Year => Some((
+                NaiveDate::from_ymd_opt(date.year(), 1, 1)?,
+                NaiveDate::from_ymd_opt(date.year(), 12, 31)?,
+            )),
+            GoalPeriod::Custom => {
+                let start =
+                    NaiveDate::parse_from_str(goal.start_date.as_ref()?, "%Y-%m-%d").ok()?;
+                let end = NaiveDate::parse_from_str(goal.end_date.as_ref()?, "%Y-%m-%d").ok()?;
+                Some((start, end))
+            }
+        }
+    }
+
+    pub fn measure(goal: &Goal, habits: &[Habit], start: NaiveDate, end: NaiveDate) -> f64 {
+        let matching: Vec<(NaiveDate, &Habit)> = habits
+            .iter()
+            .filter(|h| goal.scope.matches(h))
+            .map(|h| (h.timestamp.with_timezone(&Local).date_naive(), h))
+            .filter(|(date, _)| *date >= start && *date <= end)
+            .collect();
+
+        match goal.metric {
+            GoalMetric::Count => matching.len() as f64,
+            GoalMetric::ActiveDays => matching
+                .iter()
+                .map(|(date, _)| *date)
+                .collect::<HashSet<_>>()
+                .len() as f64,
+            GoalMetric::Minutes => matching
+                .iter()
+                .filter_map(|(_, h)| h.duration_minutes())
+                .sum(),
+        }
+    }
+
+    pub fn progress(goal: &Goal, habits: &[Habit], today: NaiveDate) -> Option<GoalProgress> {
+        let (start, end) = Self::period_bounds(goal, today)?;
+        let current = Self::measure(goal, habits, start, end.min(today));
+
+        let days_total = (end - start).num_days() + 1;
+        let days_elapsed = ((today.min(end) - start).num_days() + 1).clamp(0, days_total);
+
+        let projected = if days_elapsed >= days_total {
+            current
+        } else if days_elapsed > 0 {
+            current / days_elapsed as f64 * days_total as f64
+        } else {
+            0.0
+        };
+
+        Some(GoalProgress {
+            goal: goal.clone(),
+            period_start: start.to_string(),
+            period_end: end.to_string(),
+            current,
+            percentage: Self::percentage(current, goal.target),
+            projected,
+            on_track: projected >= goal.target,
+            met: current >= goal.target,
+            days_elapsed,
+            days_total,
+        })
+    }
+
+    // Riwayat periode yang sudah selesai sejak goal dibuat
+    pub fn history(goal: &Goal, habits: &[Habit], today: NaiveDate) -> GoalHistory {
+        let created = goal.created_at.with_timezone(&Local).date_naive();
+        let mut periods = Vec::new();
+
+        if goal.period == GoalPeriod::Custom {
+            if let Some((start, end)) = Self::period_bounds(goal, today) {
+                if end < today {
+                    periods.push(Self::period_result(goal, habits, start, end));
+                }
+            }
+        } else {
+            let mut cursor = created;
+            while let Some((start, end)) = Self::period_bounds(goal, cursor) {
+                if end >= today {
+                    break;
+                }
+                periods.push(Self::period_result(goal, habits, start, end));
+                cursor = end + Duration::days(1);
+            }
+        }
+
+        let met_count = periods.iter().filter(|p| p.met).count();
+        GoalHistory {
+            goal: goal.clone(),
+            missed_count: periods.len() - met_count,
+            met_count,
+            periods,
+        }
+    }
+
+    fn period_result(
+        goal: &Goal,
+        habits: &[Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> GoalPeriodResult {
+        let achieved = Self::measure(goal, habits, start, end);
+        GoalPeriodResult {
+            period_start: start.to_string(),
+            period_end: end.to_string(),
+            achieved,
+            target: goal.target,
+            met: achieved >= goal.target,
+        }
+    }
+
+    fn percentage(current: f64, target: f64) -> f64 {
+        if target <= 0.0 {
+            100.0
+        } else {
+            current / target * 100.0
+        }
+    }
+}
@@ -0,0 +1,600 @@
+use chrono::{Datelike, Duration, Local, NaiveDate, Timelike, Utc, Weekday};
+use std::collections::{HashMap, HashSet};
+
+use crate::i18n::{
+    category_label, format_percent, format_quantity, text, tr, weekday_name, Locale,
+};
+use crate::models::{
+    Habit, Insight, InsightPreferences, InsightRuleInfo, InsightRuleSettings, InsightType, Severity,
+};
+use crate::statistics::{CompletionPredictor, StatisticsCalculator, DEFAULT_NEGLECT_DAYS};
+
+pub struct InsightContext<'a> {
+    pub all_habits: &'a [Habit],
+    pub habits: Vec<&'a Habit>,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub today: NaiveDate,
+    pub locale: Locale,
+    // Kosong = bobot bawaan balance_targets
+    pub balance_weights: HashMap<String, f64>,
+}
+
+impl<'a> InsightContext<'a> {
+    pub fn new(
+        all_habits: &'a [Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+        today: NaiveDate,
+        locale: Locale,
+    ) -> Self {
+        let habits = all_habits
+            .iter()
+            .filter(|h| {
+                let date = h.timestamp.with_timezone(&Local).date_naive();
+                date >= start && date <= end
+            })
+            .collect();
+
+        Self {
+            all_habits,
+            habits,
+            start,
+            end,
+            today,
+            locale,
+            balance_weights: HashMap::new(),
+        }
+    }
+
+    pub fn with_balance_weights(mut self, weights: HashMap<String, f64>) -> Self {
+        self.balance_weights = weights;
+        self
+    }
+
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+
+    pub fn active_days(&self) -> usize {
+        self.habits
+            .iter()
+            .map(|h| h.timestamp.with_timezone(&Local).date_naive())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    pub fn consistency(&self) -> f64 {
+        self.active_days() as f64 / self.days() as f64 * 100.0
+    }
+
+    pub fn includes_today(&self) -> bool {
+        self.today >= self.start && self.today <= self.end
+    }
+
+    pub fn period_key(&self) -> String {
+        format!("{}_{}", self.start, self.end)
+    }
+}
+
+pub trait InsightRule: Send + Sync {
+    fn id(&self) -> &'static str;
+    fn description(&self, locale: Locale) -> String;
+    fn default_priority(&self) -> i32;
+    fn default_cooldown_days(&self) -> u32 {
+        0
+    }
+    fn evaluate(&self, ctx: &InsightContext) -> Vec<Insight>;
+}
+
+pub struct InsightEngine {
+    rules: Vec<Box<dyn InsightRule>>,
+}
+
+impl Default for InsightEngine {
+    fn default() -> Self {
+        let mut engine = Self::new();
+        engine.register(Box::new(ConsistencyRule));
+        engine.register(Box::new(PeakHourRule));
+        engine.register(Box::new(PeakWeekdayRule));
+        engine.register(Box::new(AtRiskRule));
+        engine.register(Box::new(AnomalyRule));
+        engine.register(Box::new(NeglectedAreaRule));
+        engine
+    }
+}
+
+impl InsightEngine {
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    pub fn register(&mut self, rule: Box<dyn InsightRule>) {
+        self.rules.push(rule);
+    }
+
+    pub fn settings_for(
+        &self,
+        rule: &dyn InsightRule,
+        preferences: &InsightPreferences,
+    ) -> InsightRuleSettings {
+        preferences
+            .settings
+            .get(rule.id())
+            .cloned()
+            .unwrap_or_else(|| InsightRuleSettings {
+                rule_id: rule.id().to_string(),
+                enabled: true,
+                priority: rule.default_priority(),
+                cooldown_days: rule.default_cooldown_days(),
+            })
+    }
+
+    pub fn rules_info(
+        &self,
+        preferences: &InsightPreferences,
+        locale: Locale,
+    ) -> Vec<InsightRuleInfo> {
+        self.rules
+            .iter()
+            .map(|rule| InsightRuleInfo {
+                rule_id: rule.id().to_string(),
+                description: rule.description(locale),
+                settings: self.settings_for(rule.as_ref(), preferences),
+            })
+            .collect()
+    }
+
+    pub fn has_rule(&self, rule_id: &str) -> bool {
+        self.rules.iter().any(|r| r.id() == rule_id)
+    }
+
+    pub fn generate(&self, ctx: &InsightContext, preferences: &InsightPreferences) -> Vec<Insight> {
+        let now = Utc::now();
+        let mut shown_by_rule: HashMap<&str, Vec<&crate::models::ShownInsight>> = HashMap::new();
+        for shown in &preferences.shown {
+            shown_by_rule
+                .entry(shown.rule_id.as_str())
+                .or_default()
+                .push(shown);
+        }
+
+        let mut ranked: Vec<(i32, Insight)> = Vec::new();
+        for rule in &self.rules {
+            let settings = self.settings_for(rule.as_ref(), preferences);
+            if !settings.enabled {
+                continue;
+            }
+
+            let history = shown_by_rule.get(rule.id()).cloned().unwrap_or_default();
+            let cooldown_start = now - Duration::days(settings.cooldown_days as i64);
+
+            for mut insight in rule.evaluate(ctx) {
+                insight.rule_id = rule.id().to_string();
+                if preferences.dismissed.contains(&insight.id) {
+                    continue;
+                }
+
+                // Insight yang sudah pernah tampil boleh tampil lagi; insight baru dari
+                // rule yang sama ditahan sampai cooldown lewat
+                let already_shown = history.iter().any(|s| s.insight_id == insight.id);
+                let cooling_down = settings.cooldown_days > 0
+                    && history
+                        .iter()
+                        .any(|s| s.insight_id != insight.id && s.first_shown_at > cooldown_start);
+                if !already_shown && cooling_down {
+                    continue;
+                }
+
+                ranked.push((settings.priority, insight));
+            }
+        }
+
+        ranked.sort_by_key(|(priority, _)| std::cmp::Reverse(*priority));
+        ranked.into_iter().map(|(_, insight)| insight).collect()
+    }
+}
+
+// Teks selalu dibangun ulang dari data terstruktur, jadi angka yang tampil sama dengan metrics
+pub fn describe_insight(insight: &Insight, locale: Locale) -> String {
+    let start = NaiveDate::parse_from_str(&insight.period_start, "%Y-%m-%d").ok();
+    let end = NaiveDate::parse_from_str(&insight.period_end, "%Y-%m-%d").ok();
+
+    match insight.rule_id.as_str() {
+        "consistency" => {
+            let key = match insight.insight_type {
+                InsightType::Achievement => "insight.consistency.high",
+                InsightType::Pattern => "insight.consistency.medium",
+                _ => "insight.consistency.low",
+            };
+            tr(
+                locale,
+                key,
+                &[
+                    (
+                        "percentage",
+                        format_percent(locale, insight.metric("consistency_percentage"), 1),
+                    ),
+                    ("period", period_label(start, end, locale)),
+                ],
+            )
+        }
+        "peak_hour" => {
+            let hour = insight.metric("hour") as u32;
+            let time_of_day = match hour {
+                5..=11 => "time_of_day.morning",
+                12..=17 => "time_of_day.afternoon",
+                18..=21 => "time_of_day.evening",
+                _ => "time_of_day.late_night",
+            };
+            tr(
+                locale,
+                "insight.peak_hour",
+                &[
+                    ("time_of_day", text(locale, time_of_day)),
+                    ("hour", hour.to_string()),
+                ],
+            )
+        }
+        "peak_weekday" => {
+            let weekday =
+                Weekday::try_from(insight.metric("weekday") as u8).unwrap_or(Weekday::Mon);
+            tr(
+                locale,
+                "insight.peak_weekday",
+                &[("weekday", weekday_name(locale, weekday))],
+            )
+        }
+        "at_risk" => {
+            let streak = insight.metric("current_streak") as usize;
+            // Ikut syarat CompletionPredictor: tanpa streak berjalan, habit hanya ditandai
+            // kalau weekday_rate-nya minimal RISK_THRESHOLD
+            let key = if streak > 0 {
+                "insight.at_risk.streak"
+            } else {
+                "insight.at_risk.weekday"
+            };
+            let weekday = start.map(|d| d.weekday()).unwrap_or(Weekday::Mon);
+            tr(
+                locale,
+                key,
+                &[
+                    (
+                        "habit",
+                        insight.habit_names.first().cloned().unwrap_or_default(),
+                    ),
+                    ("streak", streak.to_string()),
+                    ("weekday", weekday_name(locale, weekday)),
+                    (
+                        "probability",
+                        format_percent(locale, insight.metric("probability_today") * 100.0, 0),
+                    ),
+                ],
+            )
+        }
+        "anomaly" => {
+            let key = if start == end {
+                "insight.anomaly.day"
+            } else {
+                "insight.anomaly.week"
+            };
+            tr(
+                locale,
+                key,
+                &[
+                    ("subject", insight_subject(insight, locale)),
+                    ("date", insight.period_start.clone()),
+                    (
+                        "observed",
+                        format_quantity(locale, insight.metric("observed")),
+                    ),
+                    (
+                        "baseline",
+                        format_quantity(locale, insight.metric("baseline_median")),
+                    ),
+                ],
+            )
+        }
+        // Area yang belum pernah dicatat tidak punya days_since_last
+        "neglected_area" => tr(
+            locale,
+            if insight.metrics.contains_key("days_since_last") {
+                "insight.neglected_area"
+            } else {
+                "insight.neglected_area.never"
+            },
+            &[
+                (
+                    "category",
+                    category_label(
+                        locale,
+                        insight.categories.first().map_or("other", |c| c.as_str()),
+                    ),
+                ),
+                (
+                    "days",
+                    (insight.metric("days_since_last") as i64).to_string(),
+                ),
+            ],
+        ),
+        "personal_record" => {
+            let kind = insight.id.split(':').nth(1).unwrap_or_default();
+            let format_value = |value: f64| match kind {
+                "best_week_completion" | "best_month_consistency" => {
+                    format_percent(locale, value, 0)
+                }
+                _ => format_quantity(locale, value),
+            };
+            tr(
+                locale,
+                &format!("insight.personal_record.{}", kind),
+                &[
+                    ("subject", insight_subject(insight, locale)),
+                    ("date", insight.period_start.clone()),
+                    ("value", format_value(insight.metric("value"))),
+                    ("previous", format_value(insight.metric("previous_value"))),
+                ],
+            )
+        }
+        _ => insight.message.clone(),
+    }
+}
+
+pub fn insight_subject(insight: &Insight, locale: Locale) -> String {
+    match (insight.habit_names.first(), insight.categories.first()) {
+        (Some(name), _) => name.clone(),
+        (None, Some(category)) => tr(
+            locale,
+            "subject.category",
+            &[("category", category_label(locale, category))],
+        ),
+        (None, None) => text(locale, "subject.overall"),
+    }
+}
+
+fn period_label(start: Option<NaiveDate>, end: Option<NaiveDate>, locale: Locale) -> String {
+    let (Some(start), Some(end)) = (start, end) else {
+        return text(locale, "period.other");
+    };
+    let is_month =
+        start.day() == 1 && (end + Duration::days(1)).day() == 1 && start.month() == end.month();
+    if is_month {
+        text(locale, "period.month")
+    } else if (end - start).num_days() == 6 && start.weekday() == Weekday::Mon {
+        text(locale, "period.week")
+    } else {
+        text(locale, "period.other")
+    }
+}
+
+pub struct ConsistencyRule;
+
+impl InsightRule for ConsistencyRule {
+    fn id(&self) -> &'static str {
+        "consistency"
+    }
+
+    fn description(&self, locale: Locale) -> String {
+        text(locale, "rule.consistency")
+    }
+
+    fn default_priority(&self) -> i32 {
+        50
+    }
+
+    fn evaluate(&self, ctx: &InsightContext) -> Vec<Insight> {
+        let consistency = ctx.consistency();
+        let (insight_type, severity) = if consistency >= 80.0 {
+            (InsightType::Achievement, Severity::Low)
+        } else if consistency >= 50.0 {
+            (InsightType::Pattern, Severity::Low)
+        } else {
+            (InsightType::Suggestion, Severity::Medium)
+        };
+
+        let mut insight = Insight::new(
+            format!("{}:{}", self.id(), ctx.period_key()),
+            self.id(),
+            insight_type,
+            severity,
+            ctx.start,
+            ctx.end,
+        );
+        insight
+            .metrics
+            .insert("consistency_percentage".to_string(), consistency);
+        insight
+            .metrics
+            .insert("active_days".to_string(), ctx.active_days() as f64);
+        insight
+            .metrics
+            .insert("total_days".to_string(), ctx.days() as f64);
+        insight.message = describe_insight(&insight, ctx.locale);
+
+        vec![insight]
+    }
+}
+
+pub struct PeakHourRule;
+
+impl InsightRule for PeakHourRule {
+    fn id(&self) -> &'static str {
+        "peak_hour"
+    }
+
+    fn description(&self, locale: Locale) -> String {
+        text(locale, "rule.peak_hour")
+    }
+
+    fn default_priority(&self) -> i32 {
+        20
+    }
+
+    fn evaluate(&self, ctx: &InsightContext) -> Vec<Insight> {
+        // MODIFIED: Fixed timezone untuk hour calculation
+        let mut hour_counts: HashMap<u32, usize> = HashMap::new();
+        for habit in &ctx.habits {
+            let hour = habit.timestamp.with_timezone(&Local).hour();
+            *hour_counts.entry(hour).or_insert(0) += 1;
+        }
+
+        let Some((most_active_hour, count)) =
+            hour_counts.into_iter().max_by_key(|(_, count)| *count)
+        else {
+            return Vec::new();
+        };
+
+        let mut insight = Insight::new(
+            format!("{}:{}", self.id(), ctx.period_key()),
+            self.id(),
+            InsightType::Pattern,
+            Severity::Low,
+            ctx.start,
+            ctx.end,
+        );
+        insight
+            .metrics
+            .insert("hour".to_string(), most_active_hour as f64);
+        insight.metrics.insert("count".to_string(), count as f64);
+        insight
+            .metrics
+            .insert("total".to_string(), ctx.habits.len() as f64);
+        insight.message = describe_insight(&insight, ctx.locale);
+
+        vec![insight]
+    }
+}
+
+pub struct PeakWeekdayRule;
+
+impl InsightRule for PeakWeekdayRule {
+    fn id(&self) -> &'static str {
+        "peak_weekday"
+    }
+
+    fn description(&self, locale: Locale) -> String {
+        text(locale, "rule.peak_weekday")
+    }
+
+    fn default_priority(&self) -> i32 {
+        10
+    }
+
+    fn evaluate(&self, ctx: &InsightContext) -> Vec<Insight> {
+        // MODIFIED: Fixed timezone untuk weekday calculation
+        let mut weekday_counts: HashMap<Weekday, usize> = HashMap::new();
+        for habit in &ctx.habits {
+            let weekday = habit.timestamp.with_timezone(&Local).weekday();
+            *weekday_counts.entry(weekday).or_insert(0) += 1;
+        }
+
+        let Some((most_active_weekday, count)) =
+            weekday_counts.into_iter().max_by_key(|(_, count)| *count)
+        else {
+            return Vec::new();
+        };
+
+        let mut insight = Insight::new(
+            format!("{}:{}", self.id(), ctx.period_key()),
+            self.id(),
+            InsightType::Pattern,
+            Severity::Low,
+            ctx.start,
+            ctx.end,
+        );
+        // 0 = Senin, sama seperti num_days_from_monday
+        insight.metrics.insert(
+            "weekday".to_string(),
+            most_active_weekday.num_days_from_monday() as f64,
+        );
+        insight.metrics.insert("count".to_string(), count as f64);
+        insight
+            .metrics
+            .insert("total".to_string(), ctx.habits.len() as f64);
+        insight.message = describe_insight(&insight, ctx.locale);
+
+        vec![insight]
+    }
+}
+pub struct AtRiskRule;
+
+impl InsightRule for AtRiskRule {
+    fn id(&self) -> &'static str {
+        "at_risk"
+    }
+
+    fn description(&self, locale: Locale) -> String {
+        text(locale, "rule.at_risk")
+    }
+
+    fn default_priority(&self) -> i32 {
+        90
+    }
+
+    fn evaluate(&self, ctx: &InsightContext) -> Vec<Insight> {
+        // Peringatan streak hanya relevan untuk periode yang sedang berjalan
+        if !ctx.includes_today() {
+            return Vec::new();
+        }
+
+        let at_risk = CompletionPredictor::predict(ctx.all_habits, ctx.today)
+            .into_iter()
+            .filter(|p| p.at_risk)
+            .collect::<Vec<_>>();
+        CompletionPredictor::warning_insights(&at_risk, ctx.today, ctx.locale)
+    }
+}
+
+pub struct AnomalyRule;
+
+impl InsightRule for AnomalyRule {
+    fn id(&self) -> &'static str {
+        "anomaly"
+    }
+
+    fn description(&self, locale: Locale) -> String {
+        text(locale, "rule.anomaly")
+    }
+
+    fn default_priority(&self) -> i32 {
+        80
+    }
+
+    fn default_cooldown_days(&self) -> u32 {
+        3
+    }
+
+    fn evaluate(&self, ctx: &InsightContext) -> Vec<Insight> {
+        let anomalies =
+            StatisticsCalculator::detect_anomalies(ctx.all_habits, ctx.start, ctx.end, ctx.today);
+        StatisticsCalculator::anomaly_insights(&anomalies, 3, ctx.locale)
+    }
+}
+
+pub struct NeglectedAreaRule;
+
+impl InsightRule for NeglectedAreaRule {
+    fn id(&self) -> &'static str {
+        "neglected_area"
+    }
+
+    fn description(&self, locale: Locale) -> String {
+        text(locale, "rule.neglected_area")
+    }
+
+    fn default_priority(&self) -> i32 {
+        70
+    }
+
+    fn evaluate(&self, ctx: &InsightContext) -> Vec<Insight> {
+        // Sama dengan laporan balance: hanya area dengan bobot target lebih dari nol
+        let areas = StatisticsCalculator::watched_categories(&ctx.balance_weights);
+        StatisticsCalculator::neglect_insights(
+            ctx.all_habits,
+            &areas,
+            ctx.today.min(ctx.end),
+            DEFAULT_NEGLECT_DAYS,
+            ctx.locale,
+        )
+    }
+}
@@ -1,3 +1,27 @@
+pub mod achievements;
+pub mod anomaly;
+pub mod balance;
 pub mod calculator;
+pub mod compliance;
+pub mod correlation;
+pub mod duration;
+pub mod goals;
+pub mod insights;
+pub mod prediction;
+pub mod records;
+pub mod series;
+pub mod sleep;
+pub mod timing;
+pub mod trend;
 
-pub use calculator::*;
\ No newline at end of file
+pub use achievements::*;
+pub use balance::*;
+pub use calculator::*;
+pub use correlation::*;
+pub use goals::*;
+pub use insights::*;
+pub use prediction::*;
+pub use records::*;
+pub use series::*;
+pub use sleep::*;
+pub use trend::*;
@@ -0,0 +1,226 @@
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::i18n::Locale;
+use crate::models::{AtRiskReport, CompletionPrediction, Habit, Insight, InsightType, Severity};
+use crate::statistics::describe_insight;
+
+const LOOKBACK_DAYS: i64 = 84;
+const MOMENTUM_DAYS: i64 = 7;
+const MIN_ENTRIES: usize = 3;
+const RISK_THRESHOLD: f64 = 0.5;
+
+// Bobot gabungan: pola per hari, momentum seminggu terakhir, jarak sejak entry terakhir
+const WEEKDAY_WEIGHT: f64 = 0.5;
+const MOMENTUM_WEIGHT: f64 = 0.3;
+const RECENCY_WEIGHT: f64 = 0.2;
+
+pub struct CompletionPredictor;
+
+impl CompletionPredictor {
+    pub fn predict(habits: &[Habit], today: NaiveDate) -> Vec<CompletionPrediction> {
+        let mut by_habit: BTreeMap<&str, (String, HashSet<NaiveDate>)> = BTreeMap::new();
+        for habit in habits {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            if date > today || date < today - Duration::days(LOOKBACK_DAYS) {
+                continue;
+            }
+            by_habit
+                .entry(habit.name.as_str())
+                .or_insert_with(|| (habit.category.as_str().to_string(), HashSet::new()))
+                .1
+                .insert(date);
+        }
+
+        let mut predictions: Vec<CompletionPrediction> = by_habit
+            .into_iter()
+            .filter(|(_, (_, dates))| dates.len() >= MIN_ENTRIES)
+            .map(|(name, (category, dates))| Self::predict_habit(name, category, &dates, today))
+            .collect();
+
+        predictions.sort_by(|a, b| {
+            b.risk_score
+                .partial_cmp(&a.risk_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        predictions
+    }
+
+    pub fn at_risk_report(habits: &[Habit], today: NaiveDate, locale: Locale) -> AtRiskReport {
+        let predictions = Self::predict(habits, today);
+        let at_risk: Vec<CompletionPrediction> =
+            predictions.iter().filter(|p| p.at_risk).cloned().collect();
+        let insights = Self::warning_insights(&at_risk, today, locale);
+
+        AtRiskReport {
+            date: today.to_string(),
+            predictions,
+            at_risk,
+            insights,
+        }
+    }
+
+    pub fn warning_insights(
+        at_risk: &[CompletionPrediction],
+        today: NaiveDate,
+        locale: Locale,
+    ) -> Vec<Insight> {
+        at_risk
+            .iter()
+            .map(|p| {
+                let severity = if p.current_streak >= 7 {
+                    Severity::High
+                } else {
+                    Severity::Medium
+                };
+                let mut insight = Insight::new(
+                    format!("at_risk:{}:{}", p.habit_name, today),
+                    "at_risk",
+                    InsightType::Warning,
+                    severity,
+                    today,
+                    today,
+                );
+                insight
+                    .metrics
+                    .insert("probability_today".to_string(), p.probability_today);
+                insight
+                    .metrics
+                    .insert("current_streak".to_string(), p.current_streak as f64);
+                insight
+                    .metrics
+                    .insert("weekday_rate".to_string(), p.weekday_rate);
+                insight
+                    .metrics
+                    .insert("days_since_last".to_string(), p.days_since_last as f64);
+                insight.habit_names.push(p.habit_name.clone());
+                insight.categories.push(p.category.clone());
+                insight.message = describe_insight(&insight, locale);
+                insight
+            })
+            .collect()
+    }
+
+    fn predict_habit(
+        name: &str,
+        category: String,
+        dates: &HashSet<NaiveDate>,
+        today: NaiveDate,
+    ) -> CompletionPrediction {
+        let first = dates.iter().min().copied().unwrap_or(today);
+        let done_today = dates.contains(&today);
+
+        let last_before_today = dates.iter().filter(|d| **d < today).max().copied();
+        let days_since_last = last_before_today
+            .map(|d| (today - d).num_days())
+            .unwrap_or(LOOKBACK_DAYS);
+
+        let typical_interval = Self::typical_interval(dates);
+        let momentum = Self::momentum(dates, first, today);
+
+        let weekday_rate = Self::weekday_rate(dates, first, today, today);
+        let recency_today = Self::recency(days_since_last, typical_interval);
+        let probability_today = if done_today {
+            1.0
+        } else {
+            Self::combine(weekday_rate, momentum, recency_today)
+        };
+
+        let tomorrow = today + Duration::days(1);
+        let gap_tomorrow = if done_today { 1 } else { days_since_last + 1 };
+        let probability_tomorrow = Self::combine(
+            Self::weekday_rate(dates, first, today, tomorrow),
+            momentum,
+            Self::recency(gap_tomorrow, typical_interval),
+        );
+
+        let current_streak = Self::habit_streak(dates, today);
+        let risk_score = if done_today {
+            0.0
+        } else {
+            (1.0 - probability_today) * (1.0 + (current_streak as f64).ln_1p())
+        };
+        let at_risk = !done_today
+            && probability_today < RISK_THRESHOLD
+            && (current_streak > 0 || weekday_rate >= RISK_THRESHOLD);
+
+        CompletionPrediction {
+            habit_name: name.to_string(),
+            category,
+            done_today,
+            probability_today,
+            probability_tomorrow,
+            weekday_rate,
+            momentum,
+            days_since_last,
+            current_streak,
+            risk_score,
+            at_risk,
+        }
+    }
+
+    fn combine(weekday_rate: f64, momentum: f64, recency: f64) -> f64 {
+        (WEEKDAY_WEIGHT * weekday_rate + MOMENTUM_WEIGHT * momentum + RECENCY_WEIGHT * recency)
+            .clamp(0.0, 1.0)
+    }
+
+    // Laplace smoothing supaya habit baru tidak langsung 0% atau 100%
+    fn weekday_rate(
+        dates: &HashSet<NaiveDate>,
+        first: NaiveDate,
+        today: NaiveDate,
+        target: NaiveDate,
+    ) -> f64 {
+        let mut total = 0;
+        let mut done = 0;
+        let mut date = first;
+        while date < today {
+            if date.weekday() == target.weekday() {
+                total += 1;
+                if dates.contains(&date) {
+                    done += 1;
+                }
+            }
+            date += Duration::days(1);
+        }
+        (done as f64 + 1.0) / (total as f64 + 2.0)
+    }
+
+    fn momentum(dates: &HashSet<NaiveDate>, first: NaiveDate, today: NaiveDate) -> f64 {
+        let start = (today - Duration::days(MOMENTUM_DAYS)).max(first);
+        let total = (today - start).num_days().max(0);
+        let done = dates.iter().filter(|d| **d >= start && **d < today).count();
+        (done as f64 + 1.0) / (total as f64 + 2.0)
+    }
+
+    fn recency(gap: i64, typical_interval: f64) -> f64 {
+        let overdue = (gap as f64 - typical_interval).max(0.0);
+        (-overdue / typical_interval).exp()
+    }
+
+    fn typical_interval(dates: &HashSet<NaiveDate>) -> f64 {
+        let first = dates.iter().min();
+        let last = dates.iter().max();
+        match (first, last) {
+            (Some(first), Some(last)) if dates.len() > 1 => {
+                ((*last - *first).num_days() as f64 / (dates.len() - 1) as f64).max(1.0)
+            }
+            _ => 1.0,
+        }
+    }
+
+    // Streak dihitung sampai kemarin, jadi belum putus kalau hari ini belum dicatat
+    fn habit_streak(dates: &HashSet<NaiveDate>, today: NaiveDate) -> usize {
+        let mut date = if dates.contains(&today) {
+            today
+        } else {
+            today - Duration::days(1)
+        };
+        let mut streak = 0;
+        while dates.contains(&date) {
+            streak += 1;
+            date -= Duration::days(1);
+        }
+        streak
+    }
+}
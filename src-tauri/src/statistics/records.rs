@@ -0,0 +1,194 @@
+use chrono::{Duration, Local, NaiveDate};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::i18n::Locale;
+use crate::models::{
+    CorrelationSubject, Habit, Insight, InsightType, NewRecord, PersonalRecord, RecordKind,
+    Severity, SubjectKind, TimeGrouping,
+};
+use crate::statistics::describe_insight;
+
+pub struct RecordTracker;
+
+impl RecordTracker {
+    pub fn personal_records(habits: &[Habit]) -> Vec<PersonalRecord> {
+        let mut groups: BTreeMap<CorrelationSubject, Vec<&Habit>> = BTreeMap::new();
+        for habit in habits {
+            let subjects = [
+                CorrelationSubject {
+                    kind: SubjectKind::Habit,
+                    name: habit.name.clone(),
+                },
+                CorrelationSubject {
+                    kind: SubjectKind::Category,
+                    name: habit.category.as_str().to_string(),
+                },
+            ];
+            for subject in subjects {
+                groups.entry(subject).or_default().push(habit);
+            }
+        }
+
+        groups
+            .into_iter()
+            .flat_map(|(subject, entries)| Self::subject_records(subject, &entries))
+            .collect()
+    }
+
+    // Hanya rekor yang mengalahkan rekor sebelumnya; entry pertama sebuah habit bukan rekor baru
+    pub fn new_records(
+        before: &[Habit],
+        after: &[Habit],
+        habit: &Habit,
+        locale: Locale,
+    ) -> Vec<NewRecord> {
+        let previous: BTreeMap<(CorrelationSubject, RecordKind), f64> =
+            Self::personal_records(before)
+                .into_iter()
+                .map(|r| ((r.subject, r.kind), r.value))
+                .collect();
+
+        Self::personal_records(after)
+            .into_iter()
+            .filter(|record| match record.subject.kind {
+                SubjectKind::Habit => record.subject.name == habit.name,
+                SubjectKind::Category => record.subject.name == habit.category.as_str(),
+            })
+            .filter_map(|record| {
+                let previous_value = *previous.get(&(record.subject.clone(), record.kind))?;
+                if record.value <= previous_value {
+                    return None;
+                }
+                let insight = Self::record_insight(&record, previous_value, locale);
+                Some(NewRecord {
+                    record,
+                    previous_value,
+                    insight,
+                })
+            })
+            .collect()
+    }
+
+    pub fn record_insight(record: &PersonalRecord, previous_value: f64, locale: Locale) -> Insight {
+        let start = NaiveDate::parse_from_str(&record.period_start, "%Y-%m-%d").unwrap_or_default();
+        let end = NaiveDate::parse_from_str(&record.period_end, "%Y-%m-%d").unwrap_or_default();
+
+        // Jenis rekor disimpan di id supaya describe_insight bisa memilih teks yang sesuai
+        let subject = match record.subject.kind {
+            SubjectKind::Habit => record.subject.name.clone(),
+            SubjectKind::Category => format!("category={}", record.subject.name),
+        };
+        let mut insight = Insight::new(
+            format!(
+                "personal_record:{}:{}:{}",
+                record.kind.as_str(),
+                record.period_start,
+                subject
+            ),
+            "personal_record",
+            InsightType::Achievement,
+            Severity::Low,
+            start,
+            end,
+        );
+        insight.metrics.insert("value".to_string(), record.value);
+        insight
+            .metrics
+            .insert("previous_value".to_string(), previous_value);
+        match record.subject.kind {
+            SubjectKind::Habit => insight.habit_names.push(record.subject.name.clone()),
+            SubjectKind::Category => insight.categories.push(record.subject.name.clone()),
+        }
+        insight.message = describe_insight(&insight, locale);
+        insight
+    }
+
+    fn subject_records(subject: CorrelationSubject, entries: &[&Habit]) -> Vec<PersonalRecord> {
+        let dated: Vec<(NaiveDate, &Habit)> = entries
+            .iter()
+            .map(|h| (h.timestamp.with_timezone(&Local).date_naive(), *h))
+            .collect();
+
+        let mut day_counts: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+        for (date, _) in &dated {
+            *day_counts.entry(*date).or_insert(0) += 1;
+        }
+        let active_days: BTreeSet<NaiveDate> = day_counts.keys().copied().collect();
+
+        let mut records = Vec::new();
+        let mut push = |kind, best: Option<(f64, NaiveDate, NaiveDate)>| {
+            if let Some((value, start, end)) = best {
+                records.push(PersonalRecord {
+                    subject: subject.clone(),
+                    kind,
+                    value,
+                    period_start: start.to_string(),
+                    period_end: end.to_string(),
+                });
+            }
+        };
+
+        push(
+            RecordKind::MostCheckInsDay,
+            Self::best(
+                day_counts
+                    .iter()
+                    .map(|(date, count)| (*count as f64, *date, *date)),
+            ),
+        );
+        push(
+            RecordKind::BestWeekCompletion,
+            Self::best_period_rate(&active_days, TimeGrouping::Week),
+        );
+        push(
+            RecordKind::BestMonthConsistency,
+            Self::best_period_rate(&active_days, TimeGrouping::Month),
+        );
+        push(
+            RecordKind::LongestSession,
+            Self::best(
+                dated
+                    .iter()
+                    .filter_map(|(date, h)| h.duration_minutes().map(|m| (m, *date, *date))),
+            ),
+        );
+        push(
+            RecordKind::LargestQuantity,
+            Self::best(
+                dated
+                    .iter()
+                    .filter_map(|(date, h)| h.quantity.map(|q| (q, *date, *date))),
+            ),
+        );
+
+        records
+    }
+
+    fn best_period_rate(
+        active_days: &BTreeSet<NaiveDate>,
+        grouping: TimeGrouping,
+    ) -> Option<(f64, NaiveDate, NaiveDate)> {
+        let mut periods: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+        for date in active_days {
+            *periods.entry(grouping.period_start(*date)).or_insert(0) += 1;
+        }
+
+        Self::best(periods.into_iter().map(|(start, days)| {
+            let end = grouping.next_period_start(start) - Duration::days(1);
+            let length = (end - start).num_days() + 1;
+            (days as f64 / length as f64 * 100.0, start, end)
+        }))
+    }
+
+    // Nilai seri dimenangkan periode paling awal, jadi rekor hanya berubah kalau benar-benar terlampaui
+    fn best(
+        candidates: impl Iterator<Item = (f64, NaiveDate, NaiveDate)>,
+    ) -> Option<(f64, NaiveDate, NaiveDate)> {
+        let mut sorted: Vec<(f64, NaiveDate, NaiveDate)> = candidates.collect();
+        sorted.sort_by_key(|(_, start, _)| *start);
+        sorted.into_iter().fold(None, |best, candidate| match best {
+            Some(current) if current.0 >= candidate.0 => Some(current),
+            _ => Some(candidate),
+        })
+    }
+}
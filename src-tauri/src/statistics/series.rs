@@ -0,0 +1,120 @@
+use chrono::{Duration, Local, NaiveDate};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::models::{Habit, SeriesFilter, SeriesPoint, SeriesReport, TimeGrouping, TrendMetric};
+use crate::statistics::TrendAnalyzer;
+
+// Data sebelum `start` ikut dihitung supaya rolling 30 hari di awal range tidak terpotong
+const WARMUP_DAYS: i64 = 29;
+// Completion rate suatu hari dibagi jumlah habit yang tercatat dalam 30 hari terakhir sampai
+// hari itu, jadi nilainya tidak bergantung pada `start` yang diminta
+const ACTIVE_DAYS: i64 = 30;
+
+pub struct SeriesBuilder;
+
+impl SeriesBuilder {
+    pub fn build(
+        habits: &[Habit],
+        metric: TrendMetric,
+        start: NaiveDate,
+        end: NaiveDate,
+        grouping: TimeGrouping,
+        filter: SeriesFilter,
+        today: NaiveDate,
+    ) -> SeriesReport {
+        // Hari setelah hari ini belum terjadi, jangan dihitung sebagai nol
+        let end = end.min(today);
+        let matching: Vec<Habit> = habits
+            .iter()
+            .filter(|h| filter.matches(h))
+            .cloned()
+            .collect();
+
+        let warmup_start = start - Duration::days(WARMUP_DAYS);
+        let daily = match metric {
+            TrendMetric::CompletionRate => Self::completion_rates(&matching, warmup_start, end),
+            _ => TrendAnalyzer::daily_series(&matching, warmup_start, end, metric),
+        };
+        let offset = |date: NaiveDate| (date - warmup_start).num_days() as usize;
+
+        let mut cumulative = 0.0;
+        let mut points = Vec::new();
+        let mut period_start = start;
+        while period_start <= end {
+            // Periode pertama dan terakhir dipotong ke batas range
+            let next = grouping.next_period_start(grouping.period_start(period_start));
+            let period_end = (next - Duration::days(1)).min(end);
+            let values = &daily[offset(period_start)..=offset(period_end)];
+
+            let value = Self::aggregate(values, metric.is_additive());
+            if metric.is_additive() {
+                cumulative += value.unwrap_or(0.0);
+            }
+
+            let last = offset(period_end);
+            points.push(SeriesPoint {
+                period_start: period_start.to_string(),
+                period_end: period_end.to_string(),
+                value,
+                rolling_7: Self::mean(&daily[last + 1 - 7..=last]),
+                rolling_30: Self::mean(&daily[last + 1 - 30..=last]),
+                cumulative: metric.is_additive().then_some(cumulative),
+            });
+            period_start = next;
+        }
+
+        SeriesReport {
+            metric,
+            grouping,
+            start: start.to_string(),
+            end: end.to_string(),
+            filter,
+            points,
+        }
+    }
+
+    fn completion_rates(habits: &[Habit], start: NaiveDate, end: NaiveDate) -> Vec<Option<f64>> {
+        let history_start = start - Duration::days(ACTIVE_DAYS - 1);
+        let mut by_date: BTreeMap<NaiveDate, HashSet<&str>> = BTreeMap::new();
+        for habit in habits {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            if date >= history_start && date <= end {
+                by_date.entry(date).or_default().insert(habit.name.as_str());
+            }
+        }
+
+        let mut series = Vec::new();
+        let mut date = start;
+        while date <= end {
+            let active: HashSet<&str> = by_date
+                .range(date - Duration::days(ACTIVE_DAYS - 1)..=date)
+                .flat_map(|(_, names)| names.iter().copied())
+                .collect();
+            let done = by_date.get(&date).map_or(0, HashSet::len);
+            series.push(Some(if active.is_empty() {
+                0.0
+            } else {
+                done as f64 / active.len() as f64
+            }));
+            date += Duration::days(1);
+        }
+        series
+    }
+
+    fn aggregate(values: &[Option<f64>], additive: bool) -> Option<f64> {
+        if additive {
+            Some(values.iter().flatten().sum())
+        } else {
+            Self::mean(values)
+        }
+    }
+
+    fn mean(values: &[Option<f64>]) -> Option<f64> {
+        let observed: Vec<f64> = values.iter().flatten().copied().collect();
+        if observed.is_empty() {
+            None
+        } else {
+            Some(observed.iter().sum::<f64>() / observed.len() as f64)
+        }
+    }
+}
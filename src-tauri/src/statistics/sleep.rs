@@ -0,0 +1,132 @@
+use chrono::{Datelike, Local, NaiveDate, Timelike, Weekday};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::models::{Category, Habit, SleepNight, SleepReport};
+use crate::statistics::CorrelationAnalyzer;
+
+pub const DEFAULT_SLEEP_TARGET_HOURS: f64 = 8.0;
+// Tidur lebih dari sehari hampir pasti salah input
+pub const MAX_SLEEP_HOURS: f64 = 24.0;
+
+const MINUTES_PER_DAY: f64 = 1440.0;
+const NOON_MINUTES: f64 = 720.0;
+
+pub struct SleepAnalyzer;
+
+impl SleepAnalyzer {
+    pub fn analyze(
+        habits: &[Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+        target_hours: f64,
+    ) -> SleepReport {
+        let nights = Self::nights(habits, start, end);
+
+        let durations: Vec<f64> = nights.iter().map(|n| n.duration_hours).collect();
+        let average_duration_hours = Self::mean(&durations).unwrap_or(0.0);
+        let sleep_debt_hours = durations.iter().map(|d| (target_hours - d).max(0.0)).sum();
+
+        let bedtimes: Vec<f64> = nights.iter().map(Self::minutes_from_noon).collect();
+        let average_bedtime = Self::mean(&bedtimes).map(Self::format_clock);
+        let bedtime_std_minutes = Self::std_dev(&bedtimes);
+
+        let (weekend_mid, weekday_mid): (Vec<&SleepNight>, Vec<&SleepNight>) =
+            nights.iter().partition(|n| n.weekend);
+        let mid_sleep = |group: &[&SleepNight]| {
+            let values: Vec<f64> = group
+                .iter()
+                .map(|n| Self::minutes_from_noon(n) + n.duration_hours * 30.0)
+                .collect();
+            Self::mean(&values)
+        };
+        let social_jetlag_hours = match (mid_sleep(&weekend_mid), mid_sleep(&weekday_mid)) {
+            (Some(weekend), Some(weekday)) => Some((weekend - weekday).abs() / 60.0),
+            _ => None,
+        };
+
+        let completions: Vec<f64> = nights
+            .iter()
+            .map(|n| n.next_day_completions as f64)
+            .collect();
+        let next_day_correlation = CorrelationAnalyzer::pearson(&durations, &completions);
+
+        SleepReport {
+            start: start.to_string(),
+            end: end.to_string(),
+            target_hours,
+            nights,
+            average_duration_hours,
+            sleep_debt_hours,
+            average_bedtime,
+            bedtime_std_minutes,
+            social_jetlag_hours,
+            next_day_correlation,
+        }
+    }
+
+    // Satu malam per tanggal bangun; tidur terpecah (misalnya terbangun) dijumlahkan
+    fn nights(habits: &[Habit], start: NaiveDate, end: NaiveDate) -> Vec<SleepNight> {
+        let mut completions: BTreeMap<NaiveDate, HashSet<&str>> = BTreeMap::new();
+        for habit in habits.iter().filter(|h| h.category != Category::Sleep) {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            completions.entry(date).or_default().insert(&habit.name);
+        }
+
+        let mut nights: BTreeMap<NaiveDate, SleepNight> = BTreeMap::new();
+        for habit in habits.iter().filter(|h| h.category == Category::Sleep) {
+            let (Some(wake_time), Some(minutes)) = (habit.end_timestamp, habit.duration_minutes())
+            else {
+                continue;
+            };
+            let date = wake_time.with_timezone(&Local).date_naive();
+            if date < start || date > end {
+                continue;
+            }
+
+            let night = nights.entry(date).or_insert_with(|| SleepNight {
+                date: date.to_string(),
+                bedtime: habit.timestamp,
+                wake_time,
+                duration_hours: 0.0,
+                weekend: matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+                next_day_completions: completions.get(&date).map_or(0, |names| names.len()),
+            });
+            night.bedtime = night.bedtime.min(habit.timestamp);
+            night.wake_time = night.wake_time.max(wake_time);
+            night.duration_hours += minutes / 60.0;
+        }
+
+        nights.into_values().collect()
+    }
+
+    // Jam tidur diukur dari jam 12 siang supaya 23:00 dan 01:00 tetap berdekatan
+    fn minutes_from_noon(night: &SleepNight) -> f64 {
+        let local = night.bedtime.with_timezone(&Local);
+        let minutes = (local.hour() * 60 + local.minute()) as f64;
+        (minutes - NOON_MINUTES).rem_euclid(MINUTES_PER_DAY)
+    }
+
+    fn format_clock(minutes_from_noon: f64) -> String {
+        let minutes = (minutes_from_noon + NOON_MINUTES)
+            .rem_euclid(MINUTES_PER_DAY)
+            .round() as u32
+            % MINUTES_PER_DAY as u32;
+        format!("{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+
+    fn mean(values: &[f64]) -> Option<f64> {
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.iter().sum::<f64>() / values.len() as f64)
+        }
+    }
+
+    fn std_dev(values: &[f64]) -> f64 {
+        let Some(mean) = Self::mean(values) else {
+            return 0.0;
+        };
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        variance.sqrt()
+    }
+}
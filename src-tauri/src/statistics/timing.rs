@@ -0,0 +1,151 @@
+use chrono::{Datelike, Duration, Local, NaiveDate, Timelike, Weekday};
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::PI;
+
+use crate::models::{Habit, TimeDrift, TimeOfDayStats, TimeSummary};
+use crate::statistics::{StatisticsCalculator, TrendAnalyzer};
+
+const MINUTES_PER_DAY: f64 = 1440.0;
+
+impl StatisticsCalculator {
+    pub fn calculate_time_of_day_stats(
+        habits: &[Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Vec<TimeOfDayStats> {
+        let mut by_habit: BTreeMap<&str, Vec<(NaiveDate, f64)>> = BTreeMap::new();
+        for habit in habits {
+            let local = habit.timestamp.with_timezone(&Local);
+            let date = local.date_naive();
+            if date < start || date > end {
+                continue;
+            }
+            let minutes = (local.hour() * 60 + local.minute()) as f64;
+            by_habit
+                .entry(habit.name.as_str())
+                .or_default()
+                .push((date, minutes));
+        }
+
+        by_habit
+            .into_iter()
+            .filter_map(|(name, entries)| {
+                let all: Vec<f64> = entries.iter().map(|(_, m)| *m).collect();
+                let overall = Self::summarize_times(&all)?;
+
+                let mut hourly_histogram = vec![0; 24];
+                for minutes in &all {
+                    hourly_histogram[(*minutes as usize / 60) % 24] += 1;
+                }
+
+                let (weekend, weekday): (Vec<_>, Vec<_>) = entries
+                    .iter()
+                    .partition(|(date, _)| matches!(date.weekday(), Weekday::Sat | Weekday::Sun));
+                let weekday: Vec<f64> = weekday.iter().map(|(_, m)| *m).collect();
+                let weekend: Vec<f64> = weekend.iter().map(|(_, m)| *m).collect();
+
+                Some(TimeOfDayStats {
+                    habit_name: name.to_string(),
+                    hourly_histogram,
+                    weekday: Self::summarize_times(&weekday),
+                    weekend: Self::summarize_times(&weekend),
+                    drift: Self::time_drift(&entries, overall.median_minutes as f64),
+                    overall,
+                })
+            })
+            .collect()
+    }
+
+    fn summarize_times(minutes: &[f64]) -> Option<TimeSummary> {
+        let (mean, resultant) = Self::circular_mean(minutes)?;
+
+        // Putar data ke sekitar rata-rata sirkular supaya median tidak rusak di tengah malam
+        let mut offsets: Vec<f64> = minutes
+            .iter()
+            .map(|m| Self::wrap_offset(m - mean))
+            .collect();
+        offsets.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let mid = offsets.len() / 2;
+        let median_offset = if offsets.len().is_multiple_of(2) {
+            (offsets[mid - 1] + offsets[mid]) / 2.0
+        } else {
+            offsets[mid]
+        };
+        let median_minutes = (mean + median_offset).rem_euclid(MINUTES_PER_DAY).round() as u32
+            % MINUTES_PER_DAY as u32;
+
+        let spread_radians = if resultant > 0.0 {
+            (-2.0 * resultant.ln()).sqrt()
+        } else {
+            PI
+        };
+
+        Some(TimeSummary {
+            count: minutes.len(),
+            median_minutes,
+            median_time: format!("{:02}:{:02}", median_minutes / 60, median_minutes % 60),
+            spread_hours: spread_radians * 24.0 / (2.0 * PI),
+        })
+    }
+
+    // Mengembalikan (rata-rata dalam menit, panjang resultan R 0..1)
+    fn circular_mean(minutes: &[f64]) -> Option<(f64, f64)> {
+        if minutes.is_empty() {
+            return None;
+        }
+
+        let (sin_sum, cos_sum) = minutes.iter().fold((0.0, 0.0), |(s, c), m| {
+            let angle = m / MINUTES_PER_DAY * 2.0 * PI;
+            (s + angle.sin(), c + angle.cos())
+        });
+        let n = minutes.len() as f64;
+        let resultant = ((sin_sum / n).powi(2) + (cos_sum / n).powi(2)).sqrt();
+        let mean =
+            (sin_sum.atan2(cos_sum) / (2.0 * PI) * MINUTES_PER_DAY).rem_euclid(MINUTES_PER_DAY);
+
+        Some((mean, resultant.min(1.0)))
+    }
+
+    fn wrap_offset(offset: f64) -> f64 {
+        let half = MINUTES_PER_DAY / 2.0;
+        (offset + half).rem_euclid(MINUTES_PER_DAY) - half
+    }
+
+    fn time_drift(entries: &[(NaiveDate, f64)], reference: f64) -> TimeDrift {
+        let first = entries.iter().map(|(d, _)| *d).min();
+        let last = entries.iter().map(|(d, _)| *d).max();
+
+        let (series, span_days) = match (first, last) {
+            (Some(first), Some(last)) => {
+                let mut per_day: HashMap<NaiveDate, Vec<f64>> = HashMap::new();
+                for (date, minutes) in entries {
+                    per_day
+                        .entry(*date)
+                        .or_default()
+                        .push(Self::wrap_offset(minutes - reference) / 60.0);
+                }
+
+                let mut series = Vec::new();
+                let mut date = first;
+                while date <= last {
+                    series.push(
+                        per_day
+                            .get(&date)
+                            .map(|hours| hours.iter().sum::<f64>() / hours.len() as f64),
+                    );
+                    date += Duration::days(1);
+                }
+                (series, (last - first).num_days() as f64)
+            }
+            _ => (Vec::new(), 0.0),
+        };
+
+        let trend = TrendAnalyzer::analyze(&series);
+
+        TimeDrift {
+            total_drift_hours: trend.slope * span_days,
+            span_weeks: span_days / 7.0,
+            trend,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use chrono::{Duration, Local, NaiveDate};
+use std::collections::HashSet;
+
+use crate::models::{Habit, Trend, TrendAnalysis, TrendMetric};
+
+// Mann-Kendall butuh minimal beberapa titik supaya variansinya bermakna
+const MIN_POINTS: usize = 4;
+const MIN_NONZERO_POINTS: usize = 3;
+const CONFIDENCE_THRESHOLD: f64 = 0.9;
+
+pub struct TrendAnalyzer;
+
+impl TrendAnalyzer {
+    // `None` berarti tidak ada observasi di hari itu (mis. tidak ada compliance tercatat)
+    pub fn analyze(series: &[Option<f64>]) -> TrendAnalysis {
+        let points: Vec<(f64, f64)> = series
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|v| (i as f64, v)))
+            .collect();
+
+        let nonzero = points.iter().filter(|(_, y)| *y != 0.0).count();
+        if points.len() < MIN_POINTS || nonzero < MIN_NONZERO_POINTS {
+            return TrendAnalysis {
+                direction: Trend::InsufficientData,
+                slope: 0.0,
+                confidence: 0.0,
+                kendall_tau: 0.0,
+                sample_size: points.len(),
+            };
+        }
+
+        let slope = Self::least_squares_slope(&points);
+        let (kendall_tau, p_value) = Self::mann_kendall(&points);
+        let confidence = 1.0 - p_value;
+
+        let direction = if confidence < CONFIDENCE_THRESHOLD || slope == 0.0 {
+            Trend::Stable
+        } else if slope > 0.0 {
+            Trend::Up
+        } else {
+            Trend::Down
+        };
+
+        TrendAnalysis {
+            direction,
+            slope,
+            confidence,
+            kendall_tau,
+            sample_size: points.len(),
+        }
+    }
+
+    pub fn daily_series(
+        habits: &[Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+        metric: TrendMetric,
+    ) -> Vec<Option<f64>> {
+        let in_range: Vec<(NaiveDate, &Habit)> = habits
+            .iter()
+            .map(|h| (h.timestamp.with_timezone(&Local).date_naive(), h))
+            .filter(|(date, _)| *date >= start && *date <= end)
+            .collect();
+
+        let tracked_habits: HashSet<&str> = in_range.iter().map(|(_, h)| h.name.as_str()).collect();
+
+        let mut series = Vec::new();
+        let mut date = start;
+        while date <= end {
+            let day: Vec<&Habit> = in_range
+                .iter()
+                .filter(|(d, _)| *d == date)
+                .map(|(_, h)| *h)
+                .collect();
+
+            let value = match metric {
+                TrendMetric::DailyCount => Some(day.len() as f64),
+                TrendMetric::Compliance => {
+                    let levels: Vec<f64> = day
+                        .iter()
+                        .filter_map(|h| h.compliance_level)
+                        .map(|l| l as f64)
+                        .collect();
+                    if levels.is_empty() {
+                        None
+                    } else {
+                        Some(levels.iter().sum::<f64>() / levels.len() as f64)
+                    }
+                }
+                TrendMetric::CompletionRate => {
+                    if tracked_habits.is_empty() {
+                        Some(0.0)
+                    } else {
+                        let done: HashSet<&str> = day.iter().map(|h| h.name.as_str()).collect();
+                        Some(done.len() as f64 / tracked_habits.len() as f64)
+                    }
+                }
+                TrendMetric::Duration => {
+                    Some(day.iter().filter_map(|h| h.duration_minutes()).sum())
+                }
+                TrendMetric::Quantity => Some(day.iter().filter_map(|h| h.quantity).sum()),
+            };
+
+            series.push(value);
+            date += Duration::days(1);
+        }
+
+        series
+    }
+
+    fn least_squares_slope(points: &[(f64, f64)]) -> f64 {
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+        let numerator: f64 = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let denominator: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+        if denominator == 0.0 {
+            0.0
+        } else {
+            numerator / denominator
+        }
+    }
+
+    // Mengembalikan (Kendall tau, p-value dua sisi) dengan koreksi nilai kembar
+    fn mann_kendall(points: &[(f64, f64)]) -> (f64, f64) {
+        let n = points.len();
+        let mut s: i64 = 0;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let diff = points[j].1 - points[i].1;
+                if diff > 0.0 {
+                    s += 1;
+                } else if diff < 0.0 {
+                    s -= 1;
+                }
+            }
+        }
+
+        let mut values: Vec<f64> = points.iter().map(|(_, y)| *y).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let mut tie_correction = 0.0;
+        let mut i = 0;
+        while i < values.len() {
+            let mut j = i + 1;
+            while j < values.len() && values[j] == values[i] {
+                j += 1;
+            }
+            let t = (j - i) as f64;
+            tie_correction += t * (t - 1.0) * (2.0 * t + 5.0);
+            i = j;
+        }
+
+        let nf = n as f64;
+        let variance = (nf * (nf - 1.0) * (2.0 * nf + 5.0) - tie_correction) / 18.0;
+        let tau = s as f64 / (nf * (nf - 1.0) / 2.0);
+
+        if variance <= 0.0 {
+            return (tau, 1.0);
+        }
+
+        let z = if s > 0 {
+            (s as f64 - 1.0) / variance.sqrt()
+        } else if s < 0 {
+            (s as f64 + 1.0) / variance.sqrt()
+        } else {
+            0.0
+        };
+
+        let p_value = (2.0 * (1.0 - Self::normal_cdf(z.abs()))).clamp(0.0, 1.0);
+        (tau, p_value)
+    }
+
+    fn normal_cdf(z: f64) -> f64 {
+        0.5 * (1.0 + Self::erf(z / std::f64::consts::SQRT_2))
+    }
+
+    // Aproksimasi Abramowitz-Stegun 7.1.26 (error < 1.5e-7)
+    fn erf(x: f64) -> f64 {
+        let sign = if x < 0.0 { -1.0 } else { 1.0 };
+        let x = x.abs();
+        let t = 1.0 / (1.0 + 0.3275911 * x);
+        let poly = t
+            * (0.254829592
+                + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+        sign * (1.0 - poly * (-x * x).exp())
+    }
+}
@@ -0,0 +1,306 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::models::{
+    normalize_tags, Category, CsvColumn, CsvColumnMapping, CsvExportOptions, CsvPreview,
+    CsvPreviewRow, Habit, RatingScale,
+};
+
+const DEFAULT_DELIMITER: char = ',';
+
+// Urutan dicoba dari yang paling spesifik; semua dianggap waktu lokal
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%d/%m/%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M",
+    "%d-%m-%Y %H:%M",
+    "%m/%d/%Y %I:%M %p",
+];
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y"];
+
+pub fn validate_date_format(format: &str) -> Result<(), String> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        Err(format!("Invalid date format: {}", format))
+    } else {
+        Ok(())
+    }
+}
+
+fn local_to_utc(naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+// Sumber yang hanya mencatat tanggal dicatat jam 12 siang waktu lokal,
+// supaya tidak bergeser hari karena zona waktu
+pub fn local_noon(date: NaiveDate) -> Option<DateTime<Utc>> {
+    local_to_utc(date.and_time(NaiveTime::from_hms_opt(12, 0, 0)?))
+}
+
+// Format custom, RFC 3339, unix timestamp (detik), lalu format bawaan.
+// Tanggal tanpa jam memakai local_noon.
+pub fn parse_timestamp(value: &str, custom_format: Option<&str>) -> Result<DateTime<Utc>, String> {
+    let value = value.trim();
+    // Format custom dulu, supaya misalnya %Y%m%d tidak terbaca sebagai unix timestamp
+    if let Some(format) = custom_format {
+        if let Some(result) = parse_with_format(value, format) {
+            return result;
+        }
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    if let Ok(seconds) = value.parse::<i64>() {
+        if let Some(dt) = DateTime::from_timestamp(seconds, 0) {
+            return Ok(dt);
+        }
+    }
+
+    for format in DATETIME_FORMATS {
+        if let Some(result) = parse_with_format(value, format) {
+            return result;
+        }
+    }
+    for format in DATE_FORMATS {
+        if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+            return local_noon(date).ok_or_else(|| format!("Invalid local time: {}", value));
+        }
+    }
+    Err(format!("Unrecognized timestamp: {}", value))
+}
+
+fn parse_with_format(value: &str, format: &str) -> Option<Result<DateTime<Utc>, String>> {
+    if let Ok(naive) = NaiveDateTime::parse_from_str(value, format) {
+        return Some(local_to_utc(naive).ok_or_else(|| format!("Invalid local time: {}", value)));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+        return Some(local_noon(date).ok_or_else(|| format!("Invalid local time: {}", value)));
+    }
+    None
+}
+
+pub struct CsvTransfer;
+
+impl CsvTransfer {
+    pub fn export(habits: &[Habit], options: &CsvExportOptions) -> Result<String, String> {
+        if let Some(format) = &options.date_format {
+            validate_date_format(format)?;
+        }
+        let columns = if options.columns.is_empty() {
+            CsvColumn::defaults()
+        } else {
+            options.columns.clone()
+        };
+
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(Self::delimiter_byte(options.delimiter)?)
+            .from_writer(Vec::new());
+        writer
+            .write_record(columns.iter().map(|c| c.header()))
+            .map_err(|e| format!("CSV error: {}", e))?;
+
+        let format_time = |dt: DateTime<Utc>| {
+            let local = dt.with_timezone(&Local);
+            match &options.date_format {
+                Some(format) => local.format(format).to_string(),
+                None => local.to_rfc3339(),
+            }
+        };
+
+        for habit in habits {
+            let record: Vec<String> = columns
+                .iter()
+                .map(|column| match column {
+                    CsvColumn::Id => habit.id.clone(),
+                    CsvColumn::Name => habit.name.clone(),
+                    CsvColumn::Category => habit.category.as_str().to_string(),
+                    CsvColumn::Timestamp => format_time(habit.timestamp),
+                    CsvColumn::EndTimestamp => {
+                        habit.end_timestamp.map(format_time).unwrap_or_default()
+                    }
+                    CsvColumn::DurationMinutes => habit
+                        .duration_minutes()
+                        .map(|m| ((m * 100.0).round() / 100.0).to_string())
+                        .unwrap_or_default(),
+                    CsvColumn::Compliance => habit
+                        .compliance_level
+                        .map(|l| l.to_string())
+                        .unwrap_or_default(),
+                    CsvColumn::Notes => habit.notes.clone().unwrap_or_default(),
+                    CsvColumn::Tags => habit.tags.join(", "),
+                    CsvColumn::Quantity => {
+                        habit.quantity.map(|q| q.to_string()).unwrap_or_default()
+                    }
+                })
+                .collect();
+            writer
+                .write_record(&record)
+                .map_err(|e| format!("CSV error: {}", e))?;
+        }
+
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| format!("CSV error: {}", e))?;
+        String::from_utf8(bytes).map_err(|e| format!("CSV error: {}", e))
+    }
+
+    // Semua baris diparse; baris yang gagal tetap dikembalikan dengan daftar error-nya
+    pub fn parse(
+        contents: &str,
+        mapping: &CsvColumnMapping,
+        scales: &HashMap<String, RatingScale>,
+    ) -> Result<CsvPreview, String> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(Self::delimiter_byte(mapping.delimiter)?)
+            .has_headers(mapping.has_header)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(contents.as_bytes());
+
+        let headers: Vec<String> = if mapping.has_header {
+            reader
+                .headers()
+                .map_err(|e| format!("CSV error: {}", e))?
+                .iter()
+                .map(|h| h.trim_start_matches('\u{feff}').to_string())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let locate = |column: &str| -> Result<usize, String> {
+            headers
+                .iter()
+                .position(|h| h.eq_ignore_ascii_case(column.trim()))
+                .or_else(|| {
+                    column
+                        .trim()
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .map(|n| n - 1)
+                })
+                .ok_or_else(|| format!("Column not found: {}", column))
+        };
+        let locate_optional = |column: &Option<String>| column.as_deref().map(locate).transpose();
+
+        let name_index = locate(&mapping.name)?;
+        let timestamp_index = locate(&mapping.timestamp)?;
+        let id_index = locate_optional(&mapping.id)?;
+        let category_index = locate_optional(&mapping.category)?;
+        let compliance_index = locate_optional(&mapping.compliance)?;
+        let notes_index = locate_optional(&mapping.notes)?;
+        let tags_index = locate_optional(&mapping.tags)?;
+
+        let mut rows = Vec::new();
+        for (index, record) in reader.records().enumerate() {
+            let row = index + 1;
+            let record = match record {
+                Ok(record) => record,
+                Err(e) => {
+                    rows.push(CsvPreviewRow {
+                        row,
+                        entry: None,
+                        errors: vec![format!("CSV error: {}", e)],
+                    });
+                    continue;
+                }
+            };
+            let field = |index: Option<usize>| {
+                index
+                    .and_then(|i| record.get(i))
+                    .filter(|value| !value.is_empty())
+            };
+
+            let mut errors = Vec::new();
+
+            let name = field(Some(name_index)).unwrap_or_default().to_string();
+            if name.is_empty() {
+                errors.push("Name is empty".to_string());
+            }
+
+            let timestamp = match field(Some(timestamp_index)) {
+                Some(value) => parse_timestamp(value, mapping.timestamp_format.as_deref())
+                    .map_err(|e| errors.push(e))
+                    .ok(),
+                None => {
+                    errors.push("Timestamp is empty".to_string());
+                    None
+                }
+            };
+
+            let category = field(category_index)
+                .or(mapping.default_category.as_deref())
+                .map(Category::from_str)
+                .unwrap_or(Category::Other);
+
+            let compliance_level = match field(compliance_index) {
+                Some(value) => match value.parse::<u8>() {
+                    Ok(level) => {
+                        let valid = scales.get(&name).map_or(Ok(()), |s| s.validate(level));
+                        match valid {
+                            Ok(()) => Some(level),
+                            Err(e) => {
+                                errors.push(e);
+                                None
+                            }
+                        }
+                    }
+                    Err(_) => {
+                        errors.push(format!("Invalid compliance level: {}", value));
+                        None
+                    }
+                },
+                None => None,
+            };
+
+            let tags: Vec<String> = field(tags_index)
+                .map(|value| value.split([',', ';', '|']).map(str::to_string).collect())
+                .unwrap_or_default();
+
+            let entry = match timestamp {
+                Some(timestamp) if errors.is_empty() => Some(Habit {
+                    id: field(id_index)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| Uuid::new_v4().to_string()),
+                    name,
+                    category,
+                    timestamp,
+                    compliance_level,
+                    notes: field(notes_index).map(str::to_string),
+                    tags: normalize_tags(&tags),
+                    end_timestamp: None,
+                    quantity: None,
+                }),
+                _ => None,
+            };
+            rows.push(CsvPreviewRow { row, entry, errors });
+        }
+
+        Ok(CsvPreview {
+            headers,
+            total_rows: rows.len(),
+            valid_rows: rows.iter().filter(|r| r.entry.is_some()).count(),
+            rows,
+        })
+    }
+
+    fn delimiter_byte(delimiter: Option<char>) -> Result<u8, String> {
+        let delimiter = delimiter.unwrap_or(DEFAULT_DELIMITER);
+        if delimiter.is_ascii() {
+            Ok(delimiter as u8)
+        } else {
+            Err(format!(
+                "Delimiter must be an ASCII character: {}",
+                delimiter
+            ))
+        }
+    }
+}
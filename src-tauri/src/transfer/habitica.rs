@@ -0,0 +1,256 @@
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::{
+    normalize_tags, Category, Habit, HabiticaImportOptions, ImportRowError, ImportedHabitSummary,
+};
+use crate::transfer::ParsedImport;
+
+// Field task berisi data pengguna yang hilang saat diimport. Field yang diisi Habitica di
+// hampir semua task (priority, frequency, streak, dst.) tidak dilaporkan supaya tidak jadi noise
+const UNSUPPORTED_TASK_FIELDS: &[&str] = &["checklist", "reminders", "daysOfMonth", "weeksOfMonth"];
+
+// Objek yang selalu ada di task; hanya dilaporkan kalau task benar-benar milik challenge/grup
+const MEMBERSHIP_TASK_FIELDS: &[&str] = &["challenge", "group"];
+
+// Bagian data pengguna yang sama sekali tidak diimport
+const IGNORED_SECTIONS: &[&str] = &[
+    "achievements",
+    "items",
+    "stats",
+    "party",
+    "inbox",
+    "rewards",
+    "challenges",
+    "webhooks",
+];
+
+pub struct HabiticaImporter;
+
+impl HabiticaImporter {
+    pub fn parse(contents: &str, options: &HabiticaImportOptions) -> Result<ParsedImport, String> {
+        let user: Value = serde_json::from_str(contents)
+            .map_err(|e| format!("Invalid Habitica export: {}", e))?;
+        let tasks = user
+            .get("tasks")
+            .ok_or_else(|| "Not a Habitica user data export: tasks are missing".to_string())?;
+
+        let tag_names: HashMap<&str, &str> = Self::array(&user, "tags")
+            .iter()
+            .filter_map(|tag| Some((Self::str(tag, "id")?, Self::str(tag, "name")?)))
+            .collect();
+
+        let mut parsed = ParsedImport::default();
+        let mut unsupported: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut row_number = 0;
+
+        for (kind, task) in Self::array(tasks, "dailys")
+            .iter()
+            .map(|t| ("daily", t))
+            .chain(Self::array(tasks, "habits").iter().map(|t| ("habit", t)))
+        {
+            let Some(task_id) = Self::str(task, "id").or_else(|| Self::str(task, "_id")) else {
+                parsed
+                    .warnings
+                    .push(format!("A Habitica {} without an id was skipped", kind));
+                continue;
+            };
+            let name = Self::str(task, "text")
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+
+            for field in UNSUPPORTED_TASK_FIELDS {
+                if task.get(*field).is_some_and(Self::is_filled) {
+                    *unsupported.entry(field).or_insert(0) += 1;
+                }
+            }
+            for field in MEMBERSHIP_TASK_FIELDS {
+                if task
+                    .get(*field)
+                    .and_then(|v| v.get("id"))
+                    .is_some_and(Self::is_filled)
+                {
+                    *unsupported.entry(field).or_insert(0) += 1;
+                }
+            }
+
+            let task_tags: Vec<String> = Self::array(task, "tags")
+                .iter()
+                .filter_map(Value::as_str)
+                .filter_map(|id| tag_names.get(id).map(|name| name.to_string()))
+                .collect();
+            let category = Self::category(&task_tags, options);
+
+            let mut summary = ImportedHabitSummary {
+                source_name: name.clone(),
+                name: name.clone(),
+                category: category.as_str().to_string(),
+                entries: 0,
+                ignored: 0,
+                notes: vec![kind.to_string()],
+            };
+
+            let history = Self::array(task, "history");
+            parsed.total_rows += history.len();
+            let mut previous_value: Option<f64> = None;
+
+            for item in history {
+                row_number += 1;
+                let value = item.get("value").and_then(Value::as_f64);
+                let delta_up = match (value, previous_value) {
+                    (Some(value), Some(previous)) => value > previous,
+                    _ => false,
+                };
+                previous_value = value.or(previous_value);
+
+                let Some(timestamp) = item.get("date").and_then(Self::timestamp) else {
+                    parsed.errors.push(ImportRowError {
+                        row: row_number,
+                        message: format!("{}: history item without a valid date", name),
+                    });
+                    continue;
+                };
+                if name.is_empty() {
+                    parsed.errors.push(ImportRowError {
+                        row: row_number,
+                        message: format!("Habitica task {} has no text", task_id),
+                    });
+                    continue;
+                }
+
+                // Export lama tidak punya completed/scoredUp, jadi kenaikan value dipakai
+                let completions = if kind == "daily" {
+                    let completed = item
+                        .get("completed")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(delta_up);
+                    usize::from(completed)
+                } else {
+                    if item
+                        .get("scoredDown")
+                        .and_then(Value::as_u64)
+                        .is_some_and(|n| n > 0)
+                    {
+                        summary.ignored += 1;
+                    }
+                    match item.get("scoredUp").and_then(Value::as_u64) {
+                        Some(up) => up as usize,
+                        None => usize::from(delta_up),
+                    }
+                };
+                // Tiap skor jadi satu entry; quantity dibiarkan kosong karena habit tanpa satuan.
+                // Skor berikutnya digeser per detik supaya tidak dianggap duplikat (nama, waktu)
+                for score in 0..completions {
+                    let mut id = format!("habitica:{}:{}", task_id, timestamp.timestamp_millis());
+                    if score > 0 {
+                        id.push_str(&format!(":{}", score));
+                    }
+                    parsed.entries.push(Habit {
+                        id,
+                        name: name.clone(),
+                        category: category.clone(),
+                        timestamp: timestamp + Duration::seconds(score as i64),
+                        compliance_level: None,
+                        notes: None,
+                        tags: normalize_tags(&task_tags),
+                        end_timestamp: None,
+                        quantity: None,
+                    });
+                    summary.entries += 1;
+                }
+            }
+
+            if summary.ignored > 0 {
+                parsed.warnings.push(format!(
+                    "{}: {} negative score(s) were not imported",
+                    name, summary.ignored
+                ));
+            }
+            parsed.habits.push(summary);
+        }
+
+        for (field, count) in unsupported {
+            parsed.warnings.push(format!(
+                "Unsupported field `{}` on {} task(s) was not imported",
+                field, count
+            ));
+        }
+        let todos = Self::array(tasks, "todos").len();
+        if todos > 0 {
+            parsed
+                .warnings
+                .push(format!("{} to-do(s) were not imported", todos));
+        }
+        let ignored: Vec<&str> = IGNORED_SECTIONS
+            .iter()
+            .copied()
+            .filter(|section| {
+                user.get(*section).is_some_and(Self::is_filled)
+                    || tasks.get(*section).is_some_and(Self::is_filled)
+            })
+            .collect();
+        if !ignored.is_empty() {
+            parsed.warnings.push(format!(
+                "Habitica data not imported: {}",
+                ignored.join(", ")
+            ));
+        }
+
+        Ok(parsed)
+    }
+
+    fn category(task_tags: &[String], options: &HabiticaImportOptions) -> Category {
+        task_tags
+            .iter()
+            .find_map(|tag| {
+                options
+                    .tag_categories
+                    .get(tag)
+                    .map(|c| Category::from_str(c))
+                    .or_else(|| {
+                        Category::ALL
+                            .iter()
+                            .find(|c| c.as_str().eq_ignore_ascii_case(tag.trim()))
+                            .cloned()
+                    })
+            })
+            .or_else(|| options.default_category.as_deref().map(Category::from_str))
+            .unwrap_or(Category::Other)
+    }
+
+    // History Habitica memakai milidetik, sebagian export memakai string ISO
+    fn timestamp(value: &Value) -> Option<DateTime<Utc>> {
+        match value {
+            Value::Number(n) => DateTime::from_timestamp_millis(n.as_f64()? as i64),
+            Value::String(s) => DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc)),
+            _ => None,
+        }
+    }
+
+    fn is_filled(value: &Value) -> bool {
+        match value {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+            Value::String(s) => !s.is_empty(),
+            Value::Array(items) => !items.is_empty(),
+            Value::Object(fields) => !fields.is_empty(),
+        }
+    }
+
+    fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+        value
+            .get(key)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn str<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+        value.get(key).and_then(Value::as_str)
+    }
+}
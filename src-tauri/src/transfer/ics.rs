@@ -0,0 +1,178 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+
+use crate::models::{Goal, GoalMetric, GoalPeriod, GoalScope, Habit, SeriesFilter};
+use crate::statistics::GoalTracker;
+
+const PRODID: &str = "-//Kelompok 6//Habit Tracker//ID";
+const UID_DOMAIN: &str = "habit-tracker.local";
+// RFC 5545 3.1: baris lebih dari 75 oktet harus dilipat
+const MAX_LINE_OCTETS: usize = 75;
+
+pub fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+pub fn format_utc(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+// Lipatan tidak boleh memotong karakter UTF-8 di tengah
+fn push_line(output: &mut String, line: &str) {
+    let mut octets = 0;
+    for ch in line.chars() {
+        let width = ch.len_utf8();
+        if octets + width > MAX_LINE_OCTETS {
+            output.push_str("\r\n ");
+            // Spasi di awal baris lanjutan ikut dihitung
+            octets = 1;
+        }
+        output.push(ch);
+        octets += width;
+    }
+    output.push_str("\r\n");
+}
+
+pub struct IcsExporter;
+
+impl IcsExporter {
+    pub fn export(habits: &[Habit], goals: &[Goal], now: DateTime<Utc>) -> String {
+        let mut output = String::new();
+        for line in [
+            "BEGIN:VCALENDAR",
+            "VERSION:2.0",
+            &format!("PRODID:{}", PRODID),
+            "CALSCALE:GREGORIAN",
+            "METHOD:PUBLISH",
+            "X-WR-CALNAME:Habit Tracker",
+        ] {
+            push_line(&mut output, line);
+        }
+
+        let dtstamp = format_utc(now);
+        for habit in habits {
+            Self::push_event(&mut output, habit, &dtstamp);
+        }
+        for goal in goals {
+            Self::push_goal(&mut output, goal, &dtstamp);
+        }
+
+        push_line(&mut output, "END:VCALENDAR");
+        output
+    }
+
+    // Goal dianggap jadwal kalau cakupannya cocok dengan filter export
+    pub fn goal_matches(goal: &Goal, filter: &SeriesFilter) -> bool {
+        if goal.archived {
+            return false;
+        }
+        match &goal.scope {
+            GoalScope::Habit(name) => {
+                filter.category.is_none()
+                    && filter.tag.is_none()
+                    && filter
+                        .habit_name
+                        .as_ref()
+                        .is_none_or(|n| n.eq_ignore_ascii_case(name))
+            }
+            GoalScope::Category(category) => {
+                filter.habit_name.is_none()
+                    && filter.tag.is_none()
+                    && filter
+                        .category
+                        .as_ref()
+                        .is_none_or(|c| c.eq_ignore_ascii_case(category))
+            }
+            GoalScope::Tag(tag) => {
+                filter.habit_name.is_none()
+                    && filter.category.is_none()
+                    && filter
+                        .tag
+                        .as_ref()
+                        .is_none_or(|t| t.trim().eq_ignore_ascii_case(tag))
+            }
+        }
+    }
+
+    fn push_event(output: &mut String, habit: &Habit, dtstamp: &str) {
+        push_line(output, "BEGIN:VEVENT");
+        push_line(
+            output,
+            &format!("UID:{}@{}", escape_text(&habit.id), UID_DOMAIN),
+        );
+        push_line(output, &format!("DTSTAMP:{}", dtstamp));
+        push_line(output, &format!("DTSTART:{}", format_utc(habit.timestamp)));
+        match habit.end_timestamp {
+            Some(end) => push_line(output, &format!("DTEND:{}", format_utc(end))),
+            // Tanpa DTEND event berakhir di waktu DTSTART (RFC 5545 3.6.1), tidak memblok jadwal
+            None => push_line(output, "TRANSP:TRANSPARENT"),
+        }
+        push_line(output, &format!("SUMMARY:{}", escape_text(&habit.name)));
+        if let Some(notes) = habit.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+            push_line(output, &format!("DESCRIPTION:{}", escape_text(notes)));
+        }
+
+        let categories: Vec<String> = std::iter::once(habit.category.as_str())
+            .chain(habit.tags.iter().map(String::as_str))
+            .map(escape_text)
+            .collect();
+        push_line(output, &format!("CATEGORIES:{}", categories.join(",")));
+        push_line(output, "STATUS:CONFIRMED");
+        push_line(output, "END:VEVENT");
+    }
+
+    fn push_goal(output: &mut String, goal: &Goal, dtstamp: &str) {
+        let created = goal.created_at.with_timezone(&Local).date_naive();
+        let Some((start, end)) = GoalTracker::period_bounds(goal, created) else {
+            return;
+        };
+        let rrule = match goal.period {
+            GoalPeriod::Week => Some("FREQ=WEEKLY"),
+            GoalPeriod::Month => Some("FREQ=MONTHLY"),
+            GoalPeriod::Year => Some("FREQ=YEARLY"),
+            GoalPeriod::Custom => None,
+        };
+        let unit = match goal.metric {
+            GoalMetric::Count => "check-in",
+            GoalMetric::ActiveDays => "active days",
+            GoalMetric::Minutes => "minutes",
+        };
+        let (_, scope) = goal.scope.parts();
+
+        push_line(output, "BEGIN:VTODO");
+        push_line(
+            output,
+            &format!("UID:goal-{}@{}", escape_text(&goal.id), UID_DOMAIN),
+        );
+        push_line(output, &format!("DTSTAMP:{}", dtstamp));
+        push_line(
+            output,
+            &format!("DTSTART;VALUE=DATE:{}", format_date(start)),
+        );
+        // DUE bertipe DATE bersifat eksklusif, jadi hari setelah akhir periode
+        push_line(
+            output,
+            &format!("DUE;VALUE=DATE:{}", format_date(end + Duration::days(1))),
+        );
+        if let Some(rrule) = rrule {
+            push_line(output, &format!("RRULE:{}", rrule));
+        }
+        push_line(output, &format!("SUMMARY:{}", escape_text(&goal.title)));
+        push_line(
+            output,
+            &format!(
+                "DESCRIPTION:{}",
+                escape_text(&format!("{}: {} {}", scope, goal.target, unit))
+            ),
+        );
+        push_line(output, "END:VTODO");
+    }
+}
@@ -0,0 +1,762 @@
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+    Weekday,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::models::{
+    normalize_tags, Category, Habit, IcsImportOptions, IcsImportRule, IcsMatchField,
+    ImportedHabitSummary,
+};
+use crate::transfer::{local_noon, ParsedImport};
+
+// Batas pengaman untuk RRULE tanpa COUNT/UNTIL, dihitung di dalam rentang import saja
+const MAX_OCCURRENCES: usize = 5000;
+const MAX_PERIODS: i64 = 50_000;
+const MAX_INTERVAL: i64 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IcsTimeKind {
+    Date,
+    Utc,
+    // Waktu mengambang atau ber-TZID, dibaca sebagai waktu lokal
+    Local,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct IcsTime {
+    value: NaiveDateTime,
+    kind: IcsTimeKind,
+}
+
+impl IcsTime {
+    fn to_utc(self) -> Option<DateTime<Utc>> {
+        match self.kind {
+            IcsTimeKind::Date => local_noon(self.value.date()),
+            IcsTimeKind::Utc => Some(Utc.from_utc_datetime(&self.value)),
+            IcsTimeKind::Local => Local
+                .from_local_datetime(&self.value)
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc)),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct IcsEvent {
+    uid: Option<String>,
+    summary: String,
+    description: Option<String>,
+    location: Option<String>,
+    categories: Vec<String>,
+    start: Option<IcsTime>,
+    end: Option<IcsTime>,
+    duration: Option<Duration>,
+    rrule: Option<String>,
+    rdates: Vec<IcsTime>,
+    exdates: Vec<IcsTime>,
+    recurrence_id: Option<IcsTime>,
+    cancelled: bool,
+}
+
+struct ContentLine {
+    name: String,
+    params: HashMap<String, String>,
+    value: String,
+}
+
+// RFC 5545 3.1: baris yang diawali spasi/tab adalah lanjutan baris sebelumnya
+fn unfold(contents: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in contents.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if !raw.is_empty() => lines.push(raw.to_string()),
+            _ => {}
+        }
+    }
+    lines
+}
+
+fn parse_content_line(line: &str) -> Option<ContentLine> {
+    // Titik dua di dalam parameter yang dikutip bukan pemisah nilai
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_uppercase();
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.trim().to_uppercase(), v.trim_matches('"').to_string()))
+        .collect();
+
+    Some(ContentLine {
+        name,
+        params,
+        value: value.to_string(),
+    })
+}
+
+fn unescape_text(value: &str) -> String {
+    let mut output = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => output.push('\n'),
+                Some(other) => output.push(other),
+                None => {}
+            }
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
+
+// Pisah di koma yang tidak di-escape (CATEGORIES, EXDATE)
+fn split_list(value: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut escaped = false;
+    for c in value.chars() {
+        match c {
+            ',' if !escaped => items.push(std::mem::take(&mut current)),
+            _ => {
+                escaped = c == '\\' && !escaped;
+                current.push(c);
+            }
+        }
+    }
+    items.push(current);
+    items
+}
+
+fn parse_time(value: &str, params: &HashMap<String, String>) -> Option<IcsTime> {
+    let value = value.trim();
+    if params.get("VALUE").is_some_and(|v| v == "DATE") || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some(IcsTime {
+            value: date.and_time(NaiveTime::MIN),
+            kind: IcsTimeKind::Date,
+        });
+    }
+    let (value, kind) = match value.strip_suffix('Z') {
+        Some(utc) => (utc, IcsTimeKind::Utc),
+        None => (value, IcsTimeKind::Local),
+    };
+    Some(IcsTime {
+        value: NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?,
+        kind,
+    })
+}
+
+// Format durasi RFC 5545 3.3.6, misalnya P1D, PT1H30M, P2W
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let value = value.strip_prefix('P')?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in value.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            _ => {
+                let n: i64 = std::mem::take(&mut number).parse().ok()?;
+                // Nilai di luar jangkauan TimeDelta dianggap durasi tidak valid
+                let part = match (c, in_time) {
+                    ('W', false) => Duration::try_weeks(n),
+                    ('D', false) => Duration::try_days(n),
+                    ('H', true) => Duration::try_hours(n),
+                    ('M', true) => Duration::try_minutes(n),
+                    ('S', true) => Duration::try_seconds(n),
+                    _ => None,
+                }?;
+                total = total.checked_add(&part)?;
+            }
+        }
+    }
+    Some(if negative { -total } else { total })
+}
+
+fn parse_events(contents: &str, warnings: &mut BTreeSet<String>) -> Result<Vec<IcsEvent>, String> {
+    // File dari Outlook dan editor Windows sering diawali BOM
+    let lines = unfold(contents.trim_start_matches('\u{feff}'));
+    if !lines
+        .first()
+        .is_some_and(|l| l.trim().eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err("Not an iCalendar file: BEGIN:VCALENDAR is missing".to_string());
+    }
+
+    let mut events = Vec::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut current: Option<IcsEvent> = None;
+
+    for line in &lines {
+        let Some(line) = parse_content_line(line) else {
+            continue;
+        };
+        match line.name.as_str() {
+            "BEGIN" => {
+                let component = line.value.trim().to_uppercase();
+                if component == "VEVENT" && stack.last().is_some_and(|c| c == "VCALENDAR") {
+                    current = Some(IcsEvent::default());
+                }
+                stack.push(component);
+                continue;
+            }
+            "END" => {
+                if stack.pop().as_deref() == Some("VEVENT") {
+                    events.extend(current.take());
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        // Properti milik VALARM di dalam VEVENT diabaikan
+        let (Some(event), Some("VEVENT")) = (current.as_mut(), stack.last().map(String::as_str))
+        else {
+            continue;
+        };
+        if let Some(tzid) = line.params.get("TZID") {
+            warnings.insert(format!("Times with TZID={} were read as local time", tzid));
+        }
+        match line.name.as_str() {
+            "UID" => event.uid = Some(line.value.trim().to_string()),
+            "SUMMARY" => event.summary = unescape_text(&line.value).trim().to_string(),
+            "DESCRIPTION" => event.description = Some(unescape_text(&line.value)),
+            "LOCATION" => event.location = Some(unescape_text(&line.value)),
+            "CATEGORIES" => event.categories.extend(
+                split_list(&line.value)
+                    .iter()
+                    .map(|c| unescape_text(c).trim().to_string()),
+            ),
+            "DTSTART" => event.start = parse_time(&line.value, &line.params),
+            "DTEND" => event.end = parse_time(&line.value, &line.params),
+            "DURATION" => event.duration = parse_duration(&line.value),
+            "RRULE" => event.rrule = Some(line.value.trim().to_uppercase()),
+            "RDATE" => event.rdates.extend(
+                split_list(&line.value)
+                    .iter()
+                    .filter_map(|v| parse_time(v, &line.params)),
+            ),
+            "EXDATE" => event.exdates.extend(
+                split_list(&line.value)
+                    .iter()
+                    .filter_map(|v| parse_time(v, &line.params)),
+            ),
+            "RECURRENCE-ID" => event.recurrence_id = parse_time(&line.value, &line.params),
+            "STATUS" => event.cancelled = line.value.trim().eq_ignore_ascii_case("CANCELLED"),
+            _ => {}
+        }
+    }
+    Ok(events)
+}
+
+struct RecurrenceRule {
+    freq: String,
+    interval: i64,
+    count: Option<usize>,
+    until: Option<NaiveDateTime>,
+    // (urutan dalam bulan, hari), misalnya 2TU = (Some(2), Tue)
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+}
+
+impl RecurrenceRule {
+    fn parse(value: &str, start: IcsTime) -> Option<Self> {
+        let parts: HashMap<&str, &str> = value
+            .split(';')
+            .filter_map(|part| part.split_once('='))
+            .collect();
+
+        let until = parts.get("UNTIL").and_then(|v| {
+            let until = parse_time(v, &HashMap::new())?;
+            // UNTIL dalam UTC dibandingkan di kerangka waktu yang sama dengan DTSTART
+            Some(match (until.kind, start.kind) {
+                (IcsTimeKind::Utc, IcsTimeKind::Local | IcsTimeKind::Date) => {
+                    until.to_utc()?.with_timezone(&Local).naive_local()
+                }
+                (IcsTimeKind::Date, _) => until.value.date().and_hms_opt(23, 59, 59)?,
+                _ => until.value,
+            })
+        });
+
+        let by_day = parts
+            .get("BYDAY")
+            .map(|v| {
+                v.split(',')
+                    .filter_map(|day| {
+                        let day = day.trim();
+                        // Awalan urutan hanya berisi ASCII, jadi pemotongan aman untuk UTF-8
+                        let name = day.trim_start_matches(|c: char| {
+                            c.is_ascii_digit() || c == '+' || c == '-'
+                        });
+                        let weekday = match name {
+                            "MO" => Weekday::Mon,
+                            "TU" => Weekday::Tue,
+                            "WE" => Weekday::Wed,
+                            "TH" => Weekday::Thu,
+                            "FR" => Weekday::Fri,
+                            "SA" => Weekday::Sat,
+                            "SU" => Weekday::Sun,
+                            _ => return None,
+                        };
+                        let ordinal = match &day[..day.len() - name.len()] {
+                            "" => None,
+                            n => Some(n.trim_start_matches('+').parse().ok()?),
+                        };
+                        Some((ordinal, weekday))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let numbers = |key: &str| -> Vec<i32> {
+            parts
+                .get(key)
+                .map(|v| v.split(',').filter_map(|n| n.trim().parse().ok()).collect())
+                .unwrap_or_default()
+        };
+
+        // INTERVAL di luar batas membuat aturan tidak didukung, bukan diam-diam diganti
+        let interval = match parts.get("INTERVAL") {
+            Some(v) => v.parse().ok().filter(|n| (1..=MAX_INTERVAL).contains(n))?,
+            None => 1,
+        };
+
+        Some(Self {
+            freq: parts.get("FREQ")?.to_string(),
+            interval,
+            count: parts.get("COUNT").and_then(|v| v.parse().ok()),
+            until,
+            by_day,
+            by_month_day: numbers("BYMONTHDAY"),
+            by_month: numbers("BYMONTH")
+                .into_iter()
+                .filter(|m| (1..=12).contains(m))
+                .map(|m| m as u32)
+                .collect(),
+        })
+    }
+
+    fn is_supported(&self) -> bool {
+        matches!(
+            self.freq.as_str(),
+            "DAILY" | "WEEKLY" | "MONTHLY" | "YEARLY"
+        )
+    }
+
+    // Jumlah periode utuh sebelum `date`, supaya seri lama tidak diulang dari DTSTART.
+    // Hanya dipakai tanpa COUNT, karena COUNT butuh semua kemunculan sejak awal
+    fn periods_before(&self, start: NaiveDate, date: NaiveDate) -> i64 {
+        if self.count.is_some() || date <= start {
+            return 0;
+        }
+        let elapsed = match self.freq.as_str() {
+            "DAILY" => (date - start).num_days(),
+            "WEEKLY" => (date - start).num_days() / 7,
+            "MONTHLY" => {
+                (date.year() as i64 - start.year() as i64) * 12 + date.month0() as i64
+                    - start.month0() as i64
+            }
+            "YEARLY" => date.year() as i64 - start.year() as i64,
+            _ => 0,
+        };
+        (elapsed / self.interval - 1).max(0)
+    }
+
+    // Kemunculan berurutan di dalam [window_start, limit] sampai COUNT atau UNTIL. Kalau
+    // melebihi batas pengaman, yang disimpan adalah yang terbaru dan nilai kedua bernilai true
+    fn expand(
+        &self,
+        start: NaiveDateTime,
+        window_start: NaiveDate,
+        limit: NaiveDateTime,
+    ) -> (Vec<NaiveDateTime>, bool) {
+        let time = start.time();
+        let mut occurrences = VecDeque::new();
+        let mut seen = 0;
+        let mut truncated = false;
+        // Pemindaian selalu sampai ke `limit`, walaupun rentangnya lebih panjang dari MAX_PERIODS
+        let first_period = self
+            .periods_before(start.date(), window_start)
+            .max(self.periods_before(start.date(), limit.date()) - MAX_PERIODS + 1);
+
+        for period in first_period..first_period + MAX_PERIODS {
+            // Tanggal di luar jangkauan chrono berarti seri sudah habis
+            let Some(mut candidates) = self.period_candidates(start.date(), period) else {
+                return (occurrences.into(), truncated);
+            };
+            candidates.sort();
+
+            for date in candidates {
+                let occurrence = date.and_time(time);
+                if occurrence < start {
+                    continue;
+                }
+                if occurrence > limit
+                    || self.until.is_some_and(|until| occurrence > until)
+                    || self.count.is_some_and(|count| seen >= count)
+                {
+                    return (occurrences.into(), truncated);
+                }
+                seen += 1;
+                if date < window_start {
+                    continue;
+                }
+                if occurrences.len() >= MAX_OCCURRENCES {
+                    occurrences.pop_front();
+                    truncated = true;
+                }
+                occurrences.push_back(occurrence);
+            }
+        }
+        (occurrences.into(), true)
+    }
+
+    fn period_candidates(&self, start: NaiveDate, period: i64) -> Option<Vec<NaiveDate>> {
+        let step = period.checked_mul(self.interval)?;
+        let weekdays: Vec<Weekday> = self.by_day.iter().map(|(_, day)| *day).collect();
+
+        Some(match self.freq.as_str() {
+            "DAILY" => {
+                let date = start.checked_add_signed(Duration::try_days(step)?)?;
+                if weekdays.is_empty() || weekdays.contains(&date.weekday()) {
+                    vec![date]
+                } else {
+                    Vec::new()
+                }
+            }
+            "WEEKLY" => {
+                let week_start = start
+                    .checked_sub_signed(Duration::days(
+                        start.weekday().num_days_from_monday() as i64
+                    ))?
+                    .checked_add_signed(Duration::try_weeks(step)?)?;
+                let days = if weekdays.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    weekdays
+                };
+                days.iter()
+                    .filter_map(|day| {
+                        week_start
+                            .checked_add_signed(Duration::days(day.num_days_from_monday() as i64))
+                    })
+                    .collect()
+            }
+            "MONTHLY" => {
+                let months =
+                    (start.year() as i64 * 12 + start.month0() as i64).checked_add(step)?;
+                let year = i32::try_from(months.div_euclid(12)).ok()?;
+                let month = months.rem_euclid(12) as u32 + 1;
+                NaiveDate::from_ymd_opt(year, month, 1)?;
+                self.month_candidates(year, month, start.day())
+            }
+            "YEARLY" => {
+                let year = start.year().checked_add(i32::try_from(step).ok()?)?;
+                NaiveDate::from_ymd_opt(year, 1, 1)?;
+                let months = if self.by_month.is_empty() {
+                    vec![start.month()]
+                } else {
+                    self.by_month.clone()
+                };
+                months
+                    .into_iter()
+                    .flat_map(|month| self.month_candidates(year, month, start.day()))
+                    .collect()
+            }
+            _ => Vec::new(),
+        })
+    }
+
+    fn month_candidates(&self, year: i32, month: u32, default_day: u32) -> Vec<NaiveDate> {
+        let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return Vec::new();
+        };
+        let days_in_month = first
+            .checked_add_months(chrono::Months::new(1))
+            .map(|next| (next - first).num_days() as i32)
+            .unwrap_or(31);
+
+        if !self.by_month_day.is_empty() {
+            return self
+                .by_month_day
+                .iter()
+                .map(|d| if *d < 0 { days_in_month + d + 1 } else { *d })
+                .filter(|d| (1..=days_in_month).contains(d))
+                .filter_map(|d| NaiveDate::from_ymd_opt(year, month, d as u32))
+                .collect();
+        }
+
+        if !self.by_day.is_empty() {
+            let mut dates = Vec::new();
+            for (ordinal, weekday) in &self.by_day {
+                let matching: Vec<NaiveDate> = (0..days_in_month)
+                    .filter_map(|offset| first.checked_add_signed(Duration::days(offset as i64)))
+                    .filter(|date| date.weekday() == *weekday)
+                    .collect();
+                match ordinal {
+                    Some(n) if *n > 0 => dates.extend(matching.get(*n as usize - 1).copied()),
+                    Some(n) if *n < 0 => dates.extend(
+                        matching
+                            .len()
+                            .checked_sub(n.unsigned_abs() as usize)
+                            .and_then(|i| matching.get(i).copied()),
+                    ),
+                    _ => dates.extend(matching),
+                }
+            }
+            return dates;
+        }
+
+        // Bulan yang tidak punya tanggal tersebut dilewati (RFC 5545 3.3.10)
+        NaiveDate::from_ymd_opt(year, month, default_day)
+            .into_iter()
+            .collect()
+    }
+}
+
+fn rule_matches(rule: &IcsImportRule, event: &IcsEvent) -> bool {
+    let needle = rule.contains.trim().to_lowercase();
+    if needle.is_empty() {
+        return false;
+    }
+    let contains = |text: Option<&str>| text.is_some_and(|t| t.to_lowercase().contains(&needle));
+    let categories = event.categories.join(",");
+
+    match rule.field {
+        IcsMatchField::Summary => contains(Some(&event.summary)),
+        IcsMatchField::Description => contains(event.description.as_deref()),
+        IcsMatchField::Location => contains(event.location.as_deref()),
+        IcsMatchField::Categories => contains(Some(&categories)),
+        IcsMatchField::Any => {
+            contains(Some(&event.summary))
+                || contains(event.description.as_deref())
+                || contains(event.location.as_deref())
+                || contains(Some(&categories))
+        }
+    }
+}
+
+// Mengumpulkan entry yang lolos jendela tanggal dan cocok dengan salah satu aturan
+struct EntryCollector<'a> {
+    options: &'a IcsImportOptions,
+    start: NaiveDate,
+    end: NaiveDate,
+    now: DateTime<Utc>,
+    parsed: ParsedImport,
+    summaries: BTreeMap<String, ImportedHabitSummary>,
+    unmatched: usize,
+}
+
+impl EntryCollector<'_> {
+    fn push(&mut self, event: &IcsEvent, uid: &str, occurrence: NaiveDateTime, start: IcsTime) {
+        self.parsed.total_rows += 1;
+        if event.cancelled {
+            return;
+        }
+        let Some(timestamp) = start.to_utc() else {
+            return;
+        };
+        let local_date = timestamp.with_timezone(&Local).date_naive();
+        if timestamp > self.now || local_date < self.start || local_date > self.end {
+            return;
+        }
+
+        let Some(rule) = self
+            .options
+            .rules
+            .iter()
+            .find(|rule| rule_matches(rule, event))
+        else {
+            self.unmatched += 1;
+            return;
+        };
+        let name = rule
+            .habit_name
+            .clone()
+            .filter(|n| !n.trim().is_empty())
+            .unwrap_or_else(|| event.summary.clone());
+        if name.trim().is_empty() {
+            self.unmatched += 1;
+            return;
+        }
+        let category = Category::from_str(&rule.category);
+
+        // Event sepanjang hari tidak punya durasi yang berarti
+        let end_timestamp = match (start.kind, event.duration, event.end, event.start) {
+            (IcsTimeKind::Date, ..) => None,
+            (_, Some(duration), ..) => timestamp.checked_add_signed(duration),
+            (_, None, Some(end), Some(original)) => {
+                timestamp.checked_add_signed(end.value - original.value)
+            }
+            _ => None,
+        }
+        .filter(|end| *end > timestamp);
+
+        self.summaries
+            .entry(name.clone())
+            .or_insert_with(|| ImportedHabitSummary {
+                source_name: rule.contains.clone(),
+                name: name.clone(),
+                category: category.as_str().to_string(),
+                entries: 0,
+                ignored: 0,
+                notes: Vec::new(),
+            })
+            .entries += 1;
+
+        self.parsed.entries.push(Habit {
+            // UID + kemunculan, jadi event yang sudah pernah diimport dilewati
+            id: format!("ics:{}:{}", uid, occurrence.format("%Y%m%dT%H%M%S")),
+            name,
+            category,
+            timestamp,
+            compliance_level: None,
+            notes: event.description.clone().filter(|d| !d.trim().is_empty()),
+            tags: normalize_tags(&rule.tags),
+            end_timestamp,
+            quantity: None,
+        });
+    }
+}
+
+pub struct IcsImporter;
+
+impl IcsImporter {
+    pub fn parse(
+        contents: &str,
+        options: &IcsImportOptions,
+        start: NaiveDate,
+        end: NaiveDate,
+        now: DateTime<Utc>,
+    ) -> Result<ParsedImport, String> {
+        let mut warnings = BTreeSet::new();
+        let events = parse_events(contents, &mut warnings)?;
+
+        // Event dengan RECURRENCE-ID menggantikan satu kemunculan dari seri dengan UID yang sama
+        let mut overrides: HashMap<(String, NaiveDateTime), IcsEvent> = HashMap::new();
+        let mut masters = Vec::new();
+        for event in events {
+            match (&event.uid, event.recurrence_id) {
+                (Some(uid), Some(recurrence_id)) => {
+                    overrides.insert((uid.clone(), recurrence_id.value), event);
+                }
+                _ => masters.push(event),
+            }
+        }
+
+        let limit = end.and_time(NaiveTime::from_hms_opt(23, 59, 59).unwrap());
+        // Satu hari lebih awal karena kemunculan masih dalam waktu DTSTART, belum waktu lokal
+        let window_start = start.pred_opt().unwrap_or(start);
+        let mut collector = EntryCollector {
+            options,
+            start,
+            end,
+            now,
+            parsed: ParsedImport::default(),
+            summaries: BTreeMap::new(),
+            unmatched: 0,
+        };
+        let mut seen_overrides: HashSet<(String, NaiveDateTime)> = HashSet::new();
+
+        for master in &masters {
+            let Some(start_time) = master.start else {
+                warnings.insert(format!(
+                    "Event \"{}\" without DTSTART was skipped",
+                    master.summary
+                ));
+                continue;
+            };
+            let uid = master.uid.clone().unwrap_or_else(|| {
+                warnings.insert("Events without UID use their summary and start as id".to_string());
+                format!("{}@{}", master.summary, start_time.value)
+            });
+
+            let mut occurrences = match master.rrule.as_deref() {
+                Some(value) => match RecurrenceRule::parse(value, start_time) {
+                    Some(rule) if rule.is_supported() => {
+                        let (occurrences, truncated) =
+                            rule.expand(start_time.value, window_start, limit);
+                        if truncated {
+                            warnings.insert(format!(
+                                "Recurring event \"{}\" has too many occurrences, only the latest {} were imported",
+                                master.summary,
+                                occurrences.len()
+                            ));
+                        }
+                        occurrences
+                    }
+                    _ => {
+                        warnings.insert(format!(
+                            "Unsupported RRULE, only the first occurrence was used: {}",
+                            value
+                        ));
+                        vec![start_time.value]
+                    }
+                },
+                None => vec![start_time.value],
+            };
+            occurrences.extend(master.rdates.iter().map(|r| r.value));
+            occurrences.retain(|o| !master.exdates.iter().any(|ex| ex.value == *o));
+            occurrences.sort();
+            occurrences.dedup();
+
+            for occurrence in occurrences {
+                let key = (uid.clone(), occurrence);
+                let original = IcsTime {
+                    value: occurrence,
+                    kind: start_time.kind,
+                };
+                // Kemunculan yang dipindah memakai DTSTART dari override-nya
+                match overrides.get(&key) {
+                    Some(replacement) => {
+                        let moved = replacement.start.unwrap_or(original);
+                        collector.push(replacement, &uid, occurrence, moved);
+                        seen_overrides.insert(key);
+                    }
+                    None => collector.push(master, &uid, occurrence, original),
+                }
+            }
+        }
+
+        // Override yang seri induknya tidak ada diperlakukan sebagai event tunggal
+        for (key, event) in &overrides {
+            if let (false, Some(event_start)) = (seen_overrides.contains(key), event.start) {
+                collector.push(event, &key.0, key.1, event_start);
+            }
+        }
+
+        if collector.unmatched > 0 {
+            warnings.insert(format!(
+                "{} event(s) did not match any rule",
+                collector.unmatched
+            ));
+        }
+        let mut parsed = collector.parsed;
+        parsed.entries.sort_by_key(|e| e.timestamp);
+        parsed.habits = collector.summaries.into_values().collect();
+        parsed.warnings = warnings.into_iter().collect();
+        Ok(parsed)
+    }
+}
@@ -0,0 +1,234 @@
+use chrono::DateTime;
+use rusqlite::{Connection, OpenFlags};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use crate::models::{Category, Habit, ImportRowError, ImportedHabitSummary, LoopImportOptions};
+use crate::transfer::{local_noon, ParsedImport};
+
+// Nilai Repetitions.value di Loop untuk habit ya/tidak
+const LOOP_NO: i64 = 0;
+const LOOP_YES_AUTO: i64 = 1;
+const LOOP_YES_MANUAL: i64 = 2;
+const LOOP_SKIP: i64 = 3;
+// Habit numerik menyimpan nilai x1000
+const LOOP_NUMERICAL_SCALE: f64 = 1000.0;
+const LOOP_TYPE_NUMERICAL: i64 = 1;
+
+struct LoopHabit {
+    id: i64,
+    // UUID hanya ada di versi Loop yang lebih baru
+    uuid: Option<String>,
+    name: String,
+    numerical: bool,
+    unit: Option<String>,
+    archived: bool,
+}
+
+pub struct LoopImporter;
+
+impl LoopImporter {
+    pub fn parse(path: &Path, options: &LoopImportOptions) -> Result<ParsedImport, String> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .map_err(|e| format!("Cannot open Loop backup: {}", e))?;
+
+        let habit_columns =
+            Self::columns(&conn, "Habits").map_err(|e| format!("Invalid Loop backup: {}", e))?;
+        let repetition_columns = Self::columns(&conn, "Repetitions")
+            .map_err(|e| format!("Invalid Loop backup: {}", e))?;
+        if habit_columns.is_empty() || repetition_columns.is_empty() {
+            return Err(
+                "Not a Loop Habit Tracker backup: Habits or Repetitions table is missing"
+                    .to_string(),
+            );
+        }
+
+        Self::parse_tables(&conn, &habit_columns, &repetition_columns, options)
+            .map_err(|e| format!("Invalid Loop backup: {}", e))
+    }
+
+    fn parse_tables(
+        conn: &Connection,
+        habit_columns: &HashSet<String>,
+        repetition_columns: &HashSet<String>,
+        options: &LoopImportOptions,
+    ) -> rusqlite::Result<ParsedImport> {
+        let optional = |columns: &HashSet<String>, name: &str, fallback: &str| {
+            if columns.contains(name) {
+                name.to_string()
+            } else {
+                fallback.to_string()
+            }
+        };
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, {}, name, {}, {}, {} FROM Habits ORDER BY position, id",
+            optional(habit_columns, "uuid", "NULL"),
+            optional(habit_columns, "type", "0"),
+            optional(habit_columns, "unit", "NULL"),
+            optional(habit_columns, "archived", "0"),
+        ))?;
+        let habits: Vec<LoopHabit> = stmt
+            .query_map([], |row| {
+                Ok(LoopHabit {
+                    id: row.get(0)?,
+                    uuid: row.get(1)?,
+                    name: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                    numerical: row.get::<_, i64>(3)? == LOOP_TYPE_NUMERICAL,
+                    unit: row
+                        .get::<_, Option<String>>(4)?
+                        .filter(|unit| !unit.trim().is_empty()),
+                    archived: row.get::<_, i64>(5)? != 0,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+
+        let mut repetitions: BTreeMap<i64, Vec<(i64, i64, Option<String>)>> = BTreeMap::new();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT habit, timestamp, value, {} FROM Repetitions ORDER BY timestamp",
+            optional(repetition_columns, "notes", "NULL"),
+        ))?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, Option<String>>(3)?,
+            ))
+        })?;
+        for (habit, timestamp, value, notes) in rows.filter_map(|r| r.ok()) {
+            repetitions
+                .entry(habit)
+                .or_default()
+                .push((timestamp, value, notes));
+        }
+
+        let mut parsed = ParsedImport::default();
+        let mut row_number = 0;
+
+        for habit in &habits {
+            let rows = repetitions.remove(&habit.id).unwrap_or_default();
+            parsed.total_rows += rows.len();
+            if habit.archived && options.skip_archived {
+                row_number += rows.len();
+                continue;
+            }
+
+            let name = habit.name.trim().to_string();
+            let category = options
+                .categories
+                .get(&habit.name)
+                .or(options.default_category.as_ref())
+                .map(|c| Category::from_str(c))
+                .unwrap_or(Category::Other);
+            let source_id = habit.uuid.clone().unwrap_or_else(|| habit.id.to_string());
+
+            let mut summary = ImportedHabitSummary {
+                source_name: habit.name.clone(),
+                name: name.clone(),
+                category: category.as_str().to_string(),
+                entries: 0,
+                ignored: 0,
+                notes: Vec::new(),
+            };
+            if habit.archived {
+                summary.notes.push("archived in Loop".to_string());
+            }
+            if let Some(unit) = &habit.unit {
+                summary.notes.push(format!("unit: {}", unit));
+            }
+
+            for (timestamp, value, notes) in rows {
+                row_number += 1;
+                if name.is_empty() {
+                    parsed.errors.push(ImportRowError {
+                        row: row_number,
+                        message: format!("Loop habit {} has no name", habit.id),
+                    });
+                    continue;
+                }
+
+                // Timestamp Loop = tengah malam UTC untuk tanggal lokal pengguna
+                let Some(date) =
+                    DateTime::from_timestamp_millis(timestamp).map(|dt| dt.date_naive())
+                else {
+                    parsed.errors.push(ImportRowError {
+                        row: row_number,
+                        message: format!("Invalid Loop timestamp: {}", timestamp),
+                    });
+                    continue;
+                };
+
+                let quantity = if habit.numerical {
+                    if value <= 0 {
+                        continue;
+                    }
+                    Some(value as f64 / LOOP_NUMERICAL_SCALE)
+                } else {
+                    match value {
+                        LOOP_YES_MANUAL => None,
+                        // Skip tidak bisa disimpan tanpa dihitung sebagai check-in
+                        LOOP_SKIP => {
+                            summary.ignored += 1;
+                            continue;
+                        }
+                        // YES_AUTO hanya hasil isian otomatis Loop, bukan check-in
+                        LOOP_NO | LOOP_YES_AUTO => continue,
+                        _ => {
+                            summary.ignored += 1;
+                            continue;
+                        }
+                    }
+                };
+
+                let Some(timestamp) = local_noon(date) else {
+                    continue;
+                };
+                parsed.entries.push(Habit {
+                    id: format!("loop:{}:{}", source_id, date),
+                    name: name.clone(),
+                    category: category.clone(),
+                    timestamp,
+                    compliance_level: None,
+                    notes: notes.filter(|n| !n.trim().is_empty()),
+                    tags: Vec::new(),
+                    end_timestamp: None,
+                    quantity,
+                });
+                summary.entries += 1;
+            }
+
+            if summary.ignored > 0 {
+                parsed.warnings.push(format!(
+                    "{}: {} skip marker(s) were not imported",
+                    summary.name, summary.ignored
+                ));
+            }
+            parsed.habits.push(summary);
+        }
+
+        let orphaned: usize = repetitions.values().map(Vec::len).sum();
+        if orphaned > 0 {
+            parsed.total_rows += orphaned;
+            parsed.warnings.push(format!(
+                "{} repetition(s) belong to habits that no longer exist",
+                orphaned
+            ));
+        }
+        Ok(parsed)
+    }
+
+    // Kosong kalau tabelnya tidak ada
+    fn columns(conn: &Connection, table: &str) -> rusqlite::Result<HashSet<String>> {
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+        let columns = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .filter_map(|r| r.ok())
+            .map(|name| name.to_lowercase())
+            .collect();
+        Ok(columns)
+    }
+}
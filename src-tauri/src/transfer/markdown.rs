@@ -0,0 +1,231 @@
+use chrono::{Duration, Local, NaiveDate};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use crate::models::{Habit, RatingScale};
+
+// Isi di antara penanda ini selalu ditulis ulang, sisa catatan pengguna tidak disentuh
+pub const SECTION_START: &str = "<!-- habit-tracker:start -->";
+pub const SECTION_END: &str = "<!-- habit-tracker:end -->";
+
+// Key frontmatter milik exporter, key lain dibiarkan apa adanya
+const MANAGED_KEYS: &[&str] = &[
+    "habit_entries",
+    "habits_done",
+    "habit_streak",
+    "habit_compliance",
+    "habit_categories",
+];
+
+pub struct DayNote {
+    pub date: NaiveDate,
+    pub entries: Vec<Habit>,
+    // Hari berturut-turut yang punya entry, berakhir di tanggal ini
+    pub streak: usize,
+    // Rata-rata compliance ternormalisasi dalam persen
+    pub compliance: Option<f64>,
+}
+
+pub struct MarkdownExporter;
+
+impl MarkdownExporter {
+    // `habits` berisi seluruh riwayat supaya streak di awal rentang tetap benar
+    pub fn day_notes(
+        habits: &[Habit],
+        start: NaiveDate,
+        end: NaiveDate,
+        scales: &HashMap<String, RatingScale>,
+    ) -> Vec<DayNote> {
+        let mut by_date: BTreeMap<NaiveDate, Vec<Habit>> = BTreeMap::new();
+        for habit in habits {
+            let date = habit.timestamp.with_timezone(&Local).date_naive();
+            by_date.entry(date).or_default().push(habit.clone());
+        }
+
+        let mut notes = Vec::new();
+        let mut streak = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for (date, mut entries) in by_date {
+            streak = match previous {
+                Some(prev) if prev + Duration::days(1) == date => streak + 1,
+                _ => 1,
+            };
+            previous = Some(date);
+            if date < start || date > end {
+                continue;
+            }
+
+            entries.sort_by_key(|h| h.timestamp);
+            let levels: Vec<f64> = entries
+                .iter()
+                .filter_map(|h| {
+                    let level = h.compliance_level?;
+                    let scale = scales.get(&h.name).cloned().unwrap_or_default();
+                    Some(scale.normalize(level))
+                })
+                .collect();
+            let compliance = (!levels.is_empty())
+                .then(|| levels.iter().sum::<f64>() / levels.len() as f64 * 100.0);
+
+            notes.push(DayNote {
+                date,
+                entries,
+                streak,
+                compliance,
+            });
+        }
+        notes
+    }
+
+    // Format berisi jam/zona waktu gagal di NaiveDate, jadi ditulis lewat write! bukan format!.
+    // Pemisah folder ditolak karena semua catatan ditulis langsung di folder tujuan
+    pub fn file_name(date: NaiveDate, format: &str) -> Result<String, String> {
+        let mut name = String::new();
+        write!(name, "{}", date.format(format))
+            .map_err(|_| format!("Invalid file name format: {}", format))?;
+        if name.contains(['/', '\\']) {
+            return Err(format!(
+                "File name format must not contain path separators: {}",
+                format
+            ));
+        }
+        if name.trim().is_empty() {
+            return Err(format!("File name format is empty: {}", format));
+        }
+        Ok(format!("{}.md", name))
+    }
+
+    // Tanpa `existing` menghasilkan file baru; kalau ada, frontmatter digabung dan
+    // hanya bagian di antara SECTION_START/SECTION_END yang diganti
+    pub fn render(
+        note: &DayNote,
+        scales: &HashMap<String, RatingScale>,
+        existing: Option<&str>,
+    ) -> String {
+        let section = Self::section(note, scales);
+        let (frontmatter, body) = match existing {
+            Some(text) => Self::split_frontmatter(text),
+            None => (Vec::new(), ""),
+        };
+        let frontmatter = Self::merge_frontmatter(&frontmatter, note);
+
+        let body = match (body.find(SECTION_START), body.find(SECTION_END)) {
+            (Some(start), Some(end)) if start < end => format!(
+                "{}{}{}",
+                &body[..start],
+                section.trim_end(),
+                &body[end + SECTION_END.len()..]
+            ),
+            _ if body.trim().is_empty() => section,
+            _ => format!("{}\n\n{}", body.trim_end(), section),
+        };
+
+        format!("---\n{}---\n{}", frontmatter, body)
+    }
+
+    fn section(note: &DayNote, scales: &HashMap<String, RatingScale>) -> String {
+        let mut lines = vec![
+            SECTION_START.to_string(),
+            "## Habits".to_string(),
+            String::new(),
+        ];
+        for habit in &note.entries {
+            let mut line = format!(
+                "- [x] {} {}",
+                habit.timestamp.with_timezone(&Local).format("%H:%M"),
+                Self::inline(&habit.name)
+            );
+            let mut details = vec![habit.category.as_str().to_string()];
+            if let Some(minutes) = habit.duration_minutes() {
+                details.push(format!("{:.0} min", minutes));
+            }
+            if let Some(quantity) = habit.quantity {
+                details.push(format!("× {}", quantity));
+            }
+            if let Some(level) = habit.compliance_level {
+                let scale = scales.get(&habit.name).cloned().unwrap_or_default();
+                details.push(format!("compliance {}", scale.label(level)));
+            }
+            line.push_str(&format!(" · {}", details.join(" · ")));
+            // Tag Obsidian tidak boleh mengandung spasi
+            for tag in &habit.tags {
+                line.push_str(&format!(" #{}", tag.replace(char::is_whitespace, "-")));
+            }
+            lines.push(line);
+
+            let notes = habit.notes.as_deref().unwrap_or_default();
+            for note_line in notes.lines().filter(|l| !l.trim().is_empty()) {
+                lines.push(format!("  - {}", Self::inline(note_line.trim())));
+            }
+        }
+        lines.push(SECTION_END.to_string());
+        lines.join("\n") + "\n"
+    }
+
+    // Teks pengguna tidak boleh menutup bagian habit lebih awal
+    fn inline(text: &str) -> String {
+        text.replace(SECTION_END, "").replace(SECTION_START, "")
+    }
+
+    // Frontmatter YAML diawali baris "---" di baris pertama file
+    fn split_frontmatter(text: &str) -> (Vec<&str>, &str) {
+        let Some(rest) = text
+            .strip_prefix("---\n")
+            .or_else(|| text.strip_prefix("---\r\n"))
+        else {
+            return (Vec::new(), text);
+        };
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\r', '\n']);
+            if content == "---" || content == "..." {
+                let lines = rest[..offset].lines().collect();
+                return (lines, &rest[offset + line.len()..]);
+            }
+            offset += line.len();
+        }
+        // Frontmatter tidak ditutup, seluruh file dianggap isi biasa
+        (Vec::new(), text)
+    }
+
+    fn merge_frontmatter(existing: &[&str], note: &DayNote) -> String {
+        let mut output = String::new();
+        let mut skipping = false;
+        let mut has_date = false;
+        for line in existing {
+            let top_level = !line.starts_with([' ', '\t', '-']) && !line.trim().is_empty();
+            if top_level {
+                let key = line.split(':').next().unwrap_or_default().trim();
+                skipping = MANAGED_KEYS.contains(&key);
+                has_date |= key == "date";
+            }
+            // Baris lanjutan (daftar/nilai bertingkat) ikut key di atasnya
+            if !skipping {
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+
+        if !has_date {
+            output.push_str(&format!("date: {}\n", note.date));
+        }
+        let mut names: Vec<&str> = note.entries.iter().map(|h| h.name.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+        let mut categories: Vec<&str> = note.entries.iter().map(|h| h.category.as_str()).collect();
+        categories.sort_unstable();
+        categories.dedup();
+
+        output.push_str(&format!("habit_entries: {}\n", note.entries.len()));
+        output.push_str(&format!("habits_done: {}\n", names.len()));
+        output.push_str(&format!("habit_streak: {}\n", note.streak));
+        if let Some(compliance) = note.compliance {
+            output.push_str(&format!("habit_compliance: {:.0}\n", compliance));
+        }
+        output.push_str("habit_categories:\n");
+        for category in categories {
+            output.push_str(&format!("  - {}\n", category));
+        }
+        output
+    }
+}
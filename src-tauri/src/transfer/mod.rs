@@ -0,0 +1,17 @@
+pub mod csv_file;
+pub mod habitica;
+pub mod ics;
+pub mod ics_import;
+pub mod loop_habit;
+pub mod markdown;
+pub mod pdf_report;
+pub mod pipeline;
+
+pub use csv_file::*;
+pub use habitica::*;
+pub use ics::*;
+pub use ics_import::*;
+pub use loop_habit::*;
+pub use markdown::*;
+pub use pdf_report::*;
+pub use pipeline::*;
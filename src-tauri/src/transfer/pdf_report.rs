@@ -0,0 +1,429 @@
+use chrono::{Datelike, NaiveDate};
+use printpdf::{
+    Color, IndirectFontRef, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference, Rect, Rgb,
+};
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+use crate::charts::{
+    category_color, weekday_short, ChartTheme, MonthActivity, FONT_BOLD, FONT_REGULAR,
+};
+use crate::i18n::{format_number, format_percent, text, tr, Locale};
+use crate::models::{MonthlyStats, Trend};
+
+// A4 potret, semua ukuran dalam mm
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 18.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+const PT_TO_MM: f32 = 0.3528;
+// Perkiraan lebar rata-rata glyph DejaVu Sans (x ukuran font), dipakai untuk memotong baris
+const AVERAGE_GLYPH_WIDTH: f32 = 0.55;
+
+// Cetakan selalu memakai tema terang
+const THEME: ChartTheme = ChartTheme::LIGHT;
+
+fn rgb(hex: u32) -> Color {
+    let channel = |shift: u32| ((hex >> shift) & 0xff) as f32 / 255.0;
+    Color::Rgb(Rgb::new(channel(16), channel(8), channel(0), None))
+}
+
+fn wrap(value: &str, size: f32, width: f32) -> Vec<String> {
+    let max_chars = (width / (size * PT_TO_MM * AVERAGE_GLYPH_WIDTH)).max(1.0) as usize;
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in value.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > max_chars {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+// Kursor `y` dihitung dari atas halaman; printpdf memakai titik asal di kiri bawah
+struct PageWriter {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    y: f32,
+}
+
+impl PageWriter {
+    fn new(title: &str) -> Result<Self, String> {
+        let (doc, page, layer) = PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "report");
+        let regular = doc
+            .add_external_font(Cursor::new(FONT_REGULAR))
+            .map_err(|e| format!("PDF error: {}", e))?;
+        let bold = doc
+            .add_external_font(Cursor::new(FONT_BOLD))
+            .map_err(|e| format!("PDF error: {}", e))?;
+        let layer = doc.get_page(page).get_layer(layer);
+        Ok(Self {
+            doc,
+            layer,
+            regular,
+            bold,
+            y: MARGIN,
+        })
+    }
+
+    // Pindah ke halaman baru kalau blok setinggi `height` tidak muat lagi
+    fn reserve(&mut self, height: f32) {
+        if self.y + height > PAGE_HEIGHT - MARGIN {
+            let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "report");
+            self.layer = self.doc.get_page(page).get_layer(layer);
+            self.y = MARGIN;
+        }
+    }
+
+    // `top` adalah garis dasar teks, diukur dari atas halaman
+    fn text(&self, value: &str, size: f32, x: f32, top: f32, bold: bool, color: u32) {
+        self.layer.set_fill_color(rgb(color));
+        let font = if bold { &self.bold } else { &self.regular };
+        self.layer
+            .use_text(value, size, Mm(x), Mm(PAGE_HEIGHT - top), font);
+    }
+
+    fn rect(&self, x: f32, top: f32, width: f32, height: f32, color: u32) {
+        self.layer.set_fill_color(rgb(color));
+        self.layer.add_rect(Rect::new(
+            Mm(x),
+            Mm(PAGE_HEIGHT - top - height),
+            Mm(x + width),
+            Mm(PAGE_HEIGHT - top),
+        ));
+    }
+
+    fn heading(&mut self, value: &str) {
+        self.reserve(14.0);
+        self.y += 9.0;
+        self.text(value, 13.0, MARGIN, self.y, true, THEME.text);
+        self.y += 5.0;
+    }
+
+    fn finish(self) -> Result<Vec<u8>, String> {
+        self.doc
+            .save_to_bytes()
+            .map_err(|e| format!("PDF error: {}", e))
+    }
+}
+
+pub struct PdfReport;
+
+impl PdfReport {
+    pub fn render(
+        stats: &MonthlyStats,
+        month: u32,
+        locale: Locale,
+        generated: NaiveDate,
+    ) -> Result<Vec<u8>, String> {
+        let activity = MonthActivity::from_stats(stats, month)
+            .ok_or_else(|| format!("Invalid month: {}", month))?;
+        let title = format!(
+            "{} – {} {}",
+            text(locale, "report.title"),
+            stats.month,
+            stats.year
+        );
+
+        let mut writer = PageWriter::new(&title)?;
+        Self::summary(&mut writer, stats, &title, locale, generated);
+        Self::heatmap(&mut writer, &activity, locale);
+        Self::category_bars(&mut writer, &activity.categories, locale);
+        Self::weekly_table(&mut writer, stats, month, locale);
+        Self::insights(&mut writer, stats, locale);
+        writer.finish()
+    }
+
+    fn summary(
+        writer: &mut PageWriter,
+        stats: &MonthlyStats,
+        title: &str,
+        locale: Locale,
+        generated: NaiveDate,
+    ) {
+        writer.y += 8.0;
+        writer.text(title, 20.0, MARGIN, writer.y, true, THEME.text);
+        writer.y += 6.0;
+        writer.text(
+            &tr(
+                locale,
+                "report.generated",
+                &[("date", generated.format("%Y-%m-%d").to_string())],
+            ),
+            9.0,
+            MARGIN,
+            writer.y,
+            false,
+            THEME.muted,
+        );
+        writer.y += 6.0;
+
+        let category = match stats.most_common_category.as_str() {
+            "none" => "–".to_string(),
+            name => text(locale, &format!("category.{}", name)),
+        };
+        let cards = [
+            (
+                text(locale, "report.total_entries"),
+                format_number(locale, stats.total_habits as f64, 0),
+            ),
+            (
+                text(locale, "report.consistency"),
+                format_percent(locale, stats.consistency_percentage, 1),
+            ),
+            (text(locale, "report.most_common_category"), category),
+        ];
+        let gap = 4.0;
+        let card_width = (CONTENT_WIDTH - gap * 2.0) / 3.0;
+        for (i, (label, value)) in cards.iter().enumerate() {
+            let x = MARGIN + i as f32 * (card_width + gap);
+            writer.rect(x, writer.y, card_width, 18.0, THEME.surface);
+            writer.rect(x, writer.y, 1.2, 18.0, THEME.primary);
+            writer.text(label, 8.5, x + 4.0, writer.y + 6.0, false, THEME.muted);
+            writer.text(value, 14.0, x + 4.0, writer.y + 14.0, true, THEME.text);
+        }
+        writer.y += 18.0;
+    }
+
+    // Kalender Senin-Minggu, intensitas warna mengikuti jumlah entry per hari
+    fn heatmap(writer: &mut PageWriter, activity: &MonthActivity, locale: Locale) {
+        let offset = activity.first_day.weekday().num_days_from_monday() as i64;
+        let rows = (offset + activity.daily.len() as i64 + 6) / 7;
+        let cell = 11.0;
+        let gap = 1.5;
+
+        writer.heading(&text(locale, "report.heatmap"));
+        writer.reserve(6.0 + rows as f32 * (cell + gap));
+        for weekday in 0..7 {
+            let x = MARGIN + weekday as f32 * (cell + gap);
+            let name = weekday_short(locale, weekday);
+            writer.text(&name, 7.5, x + 1.0, writer.y + 3.5, false, THEME.muted);
+        }
+        writer.y += 5.0;
+
+        let max = activity.daily.values().copied().max().unwrap_or(0).max(1);
+        for (date, count) in &activity.daily {
+            let index = offset + (*date - activity.first_day).num_days();
+            let x = MARGIN + (index % 7) as f32 * (cell + gap);
+            let top = writer.y + (index / 7) as f32 * (cell + gap);
+            let intensity = *count as f32 / max as f32;
+            let color = THEME.scale(intensity);
+            let label_color = if intensity > 0.5 {
+                THEME.background
+            } else {
+                THEME.text
+            };
+            writer.rect(x, top, cell, cell, color);
+            writer.text(
+                &date.day().to_string(),
+                7.0,
+                x + 1.2,
+                top + 3.5,
+                false,
+                label_color,
+            );
+            if *count > 0 {
+                writer.text(
+                    &count.to_string(),
+                    8.0,
+                    x + 1.2,
+                    top + cell - 1.8,
+                    true,
+                    label_color,
+                );
+            }
+        }
+
+        // Legenda di sebelah kanan kalender
+        let legend_x = MARGIN + 7.0 * (cell + gap) + 8.0;
+        for (i, t) in [0.0, 0.33, 0.66, 1.0].iter().enumerate() {
+            writer.rect(
+                legend_x + i as f32 * 6.0,
+                writer.y,
+                5.0,
+                5.0,
+                THEME.scale(*t),
+            );
+        }
+        writer.text(
+            &format!("0 – {}", max),
+            7.5,
+            legend_x,
+            writer.y + 9.5,
+            false,
+            THEME.muted,
+        );
+        writer.y += rows as f32 * (cell + gap);
+    }
+
+    fn category_bars(
+        writer: &mut PageWriter,
+        categories: &BTreeMap<String, usize>,
+        locale: Locale,
+    ) {
+        writer.heading(&text(locale, "report.categories"));
+        let mut rows: Vec<(&String, &usize)> = categories.iter().filter(|(_, c)| **c > 0).collect();
+        rows.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        if rows.is_empty() {
+            writer.reserve(6.0);
+            writer.y += 5.0;
+            writer.text(
+                &text(locale, "report.no_data"),
+                9.5,
+                MARGIN,
+                writer.y,
+                false,
+                THEME.muted,
+            );
+            return;
+        }
+
+        let label_width = 38.0;
+        let bar_height = 5.0;
+        let max = rows.iter().map(|(_, c)| **c).max().unwrap_or(1) as f32;
+        let bar_space = CONTENT_WIDTH - label_width - 14.0;
+        for (category, count) in rows {
+            writer.reserve(bar_height + 3.0);
+            writer.text(
+                &text(locale, &format!("category.{}", category)),
+                9.0,
+                MARGIN,
+                writer.y + 4.0,
+                false,
+                THEME.text,
+            );
+            let width = (*count as f32 / max * bar_space).max(0.8);
+            writer.rect(
+                MARGIN + label_width,
+                writer.y,
+                width,
+                bar_height,
+                category_color(category),
+            );
+            writer.text(
+                &format_number(locale, *count as f64, 0),
+                9.0,
+                MARGIN + label_width + width + 2.0,
+                writer.y + 4.0,
+                true,
+                THEME.text,
+            );
+            writer.y += bar_height + 3.0;
+        }
+    }
+
+    fn weekly_table(writer: &mut PageWriter, stats: &MonthlyStats, month: u32, locale: Locale) {
+        writer.heading(&text(locale, "report.weekly"));
+        let row_height = 7.0;
+        let week_width = 34.0;
+        let trend_width = 30.0;
+        let total_width = 16.0;
+        let day_width = (CONTENT_WIDTH - week_width - trend_width - total_width) / 7.0;
+
+        let mut header = vec![(text(locale, "report.week"), week_width)];
+        header.extend((0..7).map(|d| (weekday_short(locale, d), day_width)));
+        header.push((text(locale, "report.total"), total_width));
+        header.push((text(locale, "report.trend"), trend_width));
+
+        writer.reserve(row_height * 2.0);
+        writer.rect(MARGIN, writer.y, CONTENT_WIDTH, row_height, THEME.surface);
+        let mut x = MARGIN;
+        for (label, width) in &header {
+            writer.text(label, 8.0, x + 1.5, writer.y + 4.8, true, THEME.muted);
+            x += width;
+        }
+        writer.y += row_height;
+
+        for week in &stats.weeks {
+            writer.reserve(row_height);
+            let range = match (
+                NaiveDate::parse_from_str(&week.week_start, "%Y-%m-%d"),
+                NaiveDate::parse_from_str(&week.week_end, "%Y-%m-%d"),
+            ) {
+                (Ok(start), Ok(end)) => {
+                    format!("{} – {}", start.format("%d/%m"), end.format("%d/%m"))
+                }
+                _ => week.week_start.clone(),
+            };
+            let trend = match week.trend {
+                Trend::Up => "up",
+                Trend::Down => "down",
+                Trend::Stable => "stable",
+                Trend::InsufficientData => "insufficient_data",
+            };
+
+            let mut cells = vec![(range, week_width, THEME.text)];
+            for day in &week.days {
+                let in_month = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d")
+                    .is_ok_and(|d| d.month() == month);
+                let (value, color) = if in_month {
+                    (day.total_habits.to_string(), THEME.text)
+                } else {
+                    ("–".to_string(), THEME.border)
+                };
+                cells.push((value, day_width, color));
+            }
+            cells.push((week.total_habits.to_string(), total_width, THEME.text));
+            cells.push((
+                text(locale, &format!("report.trend.{}", trend)),
+                trend_width,
+                THEME.muted,
+            ));
+
+            let mut x = MARGIN;
+            for (value, width, color) in &cells {
+                writer.text(value, 8.5, x + 1.5, writer.y + 4.8, false, *color);
+                x += width;
+            }
+            writer.y += row_height;
+            writer.rect(MARGIN, writer.y - 0.3, CONTENT_WIDTH, 0.3, THEME.border);
+        }
+    }
+
+    fn insights(writer: &mut PageWriter, stats: &MonthlyStats, locale: Locale) {
+        writer.heading(&text(locale, "report.insights"));
+        if stats.insights.is_empty() {
+            writer.reserve(6.0);
+            writer.y += 5.0;
+            writer.text(
+                &text(locale, "report.no_insights"),
+                9.5,
+                MARGIN,
+                writer.y,
+                false,
+                THEME.muted,
+            );
+            return;
+        }
+
+        let size = 9.5;
+        let line_height = 5.0;
+        let indent = 5.0;
+        for insight in &stats.insights {
+            let lines = wrap(&insight.message, size, CONTENT_WIDTH - indent);
+            writer.reserve(lines.len() as f32 * line_height + 2.0);
+            writer.rect(MARGIN, writer.y + 2.2, 1.8, 1.8, THEME.primary);
+            for line in lines {
+                writer.y += line_height;
+                writer.text(
+                    &line,
+                    size,
+                    MARGIN + indent,
+                    writer.y - 1.0,
+                    false,
+                    THEME.text,
+                );
+            }
+            writer.y += 2.0;
+        }
+    }
+}
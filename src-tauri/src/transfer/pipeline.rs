@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use crate::database::HabitDatabase;
+use crate::models::{EntryImportReport, Habit, ImportRowError, ImportedHabitSummary, RatingScale};
+
+const PREVIEW_ENTRIES: usize = 20;
+
+// Hasil parse dari sumber mana pun (CSV, Loop, ...) sebelum masuk ke database
+#[derive(Debug, Default)]
+pub struct ParsedImport {
+    pub total_rows: usize,
+    pub entries: Vec<Habit>,
+    pub errors: Vec<ImportRowError>,
+    pub warnings: Vec<String>,
+    pub habits: Vec<ImportedHabitSummary>,
+}
+
+// Level compliance harus ada di skala habit-nya, sama seperti input manual dan import CSV.
+// Habit yang belum punya skala tidak dibatasi, skala default hanya untuk tampilan
+pub fn validate_compliance(
+    habit: &Habit,
+    scales: &HashMap<String, RatingScale>,
+) -> Result<(), String> {
+    match (habit.compliance_level, scales.get(&habit.name)) {
+        (Some(level), Some(scale)) => scale.validate(level),
+        _ => Ok(()),
+    }
+}
+
+// Semua importer lewat sini supaya dry run, dedup dan validasi skalanya sama
+pub fn run_import(
+    db: &HabitDatabase,
+    source: &str,
+    parsed: ParsedImport,
+    dry_run: bool,
+) -> Result<EntryImportReport, String> {
+    let scales = db
+        .get_habit_scales()
+        .map_err(|e| format!("Database error: {}", e))?;
+    let mut warnings = parsed.warnings;
+    let entries: Vec<Habit> = parsed
+        .entries
+        .into_iter()
+        .filter(|entry| match validate_compliance(entry, &scales) {
+            Ok(()) => true,
+            Err(e) => {
+                warnings.push(format!("Entry {} was skipped: {}", entry.id, e));
+                false
+            }
+        })
+        .collect();
+
+    let preview = if dry_run {
+        entries.iter().take(PREVIEW_ENTRIES).cloned().collect()
+    } else {
+        Vec::new()
+    };
+    let counts = db
+        .import_entries(&entries, dry_run)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(EntryImportReport {
+        source: source.to_string(),
+        dry_run,
+        total_rows: parsed.total_rows,
+        counts,
+        errors: parsed.errors,
+        warnings,
+        habits: parsed.habits,
+        preview,
+    })
+}
@@ -10,6 +10,8 @@ const state = reactive({
   loading: false,
   error: null,
   categories: [],
+  locale: "id",
+  series: null,
 });
 
 const formatDateForDisplay = (timestamp) => {
@@ -99,6 +101,25 @@ const actions = {
     }
   },
 
+  async logSleep(sleepInput) {
+    state.loading = true;
+    state.error = null;
+    try {
+      const sleep = await invoke("log_sleep", { input: sleepInput });
+      state.habits.unshift(sleep);
+
+      await this.refreshAllData();
+
+      return sleep;
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to log sleep:", error);
+      throw error;
+    } finally {
+      state.loading = false;
+    }
+  },
+
   async deleteHabit(id) {
     try {
       const success = await invoke("delete_habit", { id });
@@ -137,6 +158,41 @@ const actions = {
     }
   },
 
+  // Cooldown insight dihitung dari waktu pertama tampil, jadi dicatat saat panel menampilkannya
+  async markInsightsShown(insights) {
+    try {
+      await invoke("mark_insights_shown", { insights });
+    } catch (error) {
+      console.error("Failed to record shown insights:", error);
+    }
+  },
+
+  async loadLocale() {
+    try {
+      state.locale = await invoke("get_locale");
+    } catch (error) {
+      console.error("Failed to load locale:", error);
+    }
+  },
+
+  // Insight dan nama bulan dibuat di backend, jadi statistik dimuat ulang setelah ganti bahasa
+  async setLocale(locale) {
+    try {
+      state.locale = await invoke("set_locale", { locale });
+      await this.loadMonthlyStats();
+    } catch (error) {
+      console.error("Failed to set locale:", error);
+    }
+  },
+
+  async loadSeries(metric = "daily_count", grouping = "day") {
+    try {
+      state.series = await invoke("get_series", { metric, grouping });
+    } catch (error) {
+      console.error("Failed to load series:", error);
+    }
+  },
+
   async loadCurrentStreak() {
     try {
       state.currentStreak = await invoke("get_current_streak");
@@ -145,16 +201,204 @@ const actions = {
     }
   },
 
+  async exportBackup(path) {
+    try {
+      return await invoke("export_backup", { path });
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to export backup:", error);
+      throw error;
+    }
+  },
+
+  // dryRun = true hanya mengembalikan ringkasan perubahan tanpa menyimpan apa pun
+  async importBackup(path, mode = "merge", dryRun = true) {
+    state.loading = true;
+    state.error = null;
+    try {
+      const summary = await invoke("import_backup", { path, mode, dryRun });
+      if (!dryRun) {
+        await this.loadAllData();
+      }
+      return summary;
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to import backup:", error);
+      throw error;
+    } finally {
+      state.loading = false;
+    }
+  },
+
+  async exportCsv(path, options = {}) {
+    try {
+      return await invoke("export_csv", { path, options });
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to export CSV:", error);
+      throw error;
+    }
+  },
+
+  async previewCsvImport(path, mapping, limit = 20) {
+    try {
+      return await invoke("preview_csv_import", { path, mapping, limit });
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to preview CSV import:", error);
+      throw error;
+    }
+  },
+
+  async importCsv(path, mapping, dryRun = true) {
+    state.loading = true;
+    state.error = null;
+    try {
+      const report = await invoke("import_csv", { path, mapping, dryRun });
+      if (!dryRun) {
+        await this.loadAllData();
+      }
+      return report;
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to import CSV:", error);
+      throw error;
+    } finally {
+      state.loading = false;
+    }
+  },
+
+  async importLoopBackup(path, options = {}, dryRun = true) {
+    state.loading = true;
+    state.error = null;
+    try {
+      const report = await invoke("import_loop_backup", {
+        path,
+        options,
+        dryRun,
+      });
+      if (!dryRun) {
+        await this.loadAllData();
+      }
+      return report;
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to import Loop backup:", error);
+      throw error;
+    } finally {
+      state.loading = false;
+    }
+  },
+
+  async importHabitica(path, options = {}, dryRun = true) {
+    state.loading = true;
+    state.error = null;
+    try {
+      const report = await invoke("import_habitica", { path, options, dryRun });
+      if (!dryRun) {
+        await this.loadAllData();
+      }
+      return report;
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to import Habitica data:", error);
+      throw error;
+    } finally {
+      state.loading = false;
+    }
+  },
+
+  async exportIcs(path, options = {}) {
+    try {
+      return await invoke("export_ics", { path, options });
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to export calendar:", error);
+      throw error;
+    }
+  },
+
+  // options.rules wajib diisi, misalnya [{ contains: "Gym", category: "exercise" }]
+  async importIcs(path, options, dryRun = true) {
+    state.loading = true;
+    state.error = null;
+    try {
+      const report = await invoke("import_ics", { path, options, dryRun });
+      if (!dryRun) {
+        await this.loadAllData();
+      }
+      return report;
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to import calendar:", error);
+      throw error;
+    } finally {
+      state.loading = false;
+    }
+  },
+
+  async exportMarkdownJournal(folder, options = {}) {
+    try {
+      return await invoke("export_markdown_journal", { folder, options });
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to export Markdown journal:", error);
+      throw error;
+    }
+  },
+
+  async exportMonthlyReportPdf(path, year, month) {
+    try {
+      return await invoke("export_monthly_report_pdf", {
+        path,
+        year,
+        month,
+        locale: state.locale,
+      });
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to export monthly report:", error);
+      throw error;
+    }
+  },
+
+  async renderChart(options) {
+    try {
+      return await invoke("render_chart", {
+        options: { locale: state.locale, ...options },
+      });
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to render chart:", error);
+      throw error;
+    }
+  },
+
+  async exportChart(path, options) {
+    try {
+      return await invoke("export_chart", {
+        path,
+        options: { locale: state.locale, ...options },
+      });
+    } catch (error) {
+      state.error = error;
+      console.error("Failed to export chart:", error);
+      throw error;
+    }
+  },
+
   async refreshAllData() {
     await Promise.all([
       this.loadTodayHabits(),
       this.loadWeeklyStats(),
       this.loadMonthlyStats(),
       this.loadCurrentStreak(),
+      this.loadSeries(),
     ]);
   },
 
   async loadAllData() {
+    await this.loadLocale();
     await Promise.all([
       this.loadCategories(),
       this.loadAllHabits(),
@@ -162,6 +406,7 @@ const actions = {
       this.loadWeeklyStats(),
       this.loadMonthlyStats(),
       this.loadCurrentStreak(),
+      this.loadSeries(),
     ]);
   },
 };